@@ -0,0 +1,45 @@
+use super::{cos_deg, normalize_deg, poly, sin_deg, time::JD_OFFSET};
+
+/// Mean obliquity of the ecliptic (Meeus 22.2).
+pub(crate) fn obliquity(c: f64) -> f64 {
+    poly(
+        c,
+        &[
+            23.0 + 26.0 / 60.0 + 21.448 / 3600.0,
+            -46.8150 / 3600.0,
+            -0.00059 / 3600.0,
+            0.001813 / 3600.0,
+        ],
+    )
+}
+
+/// Nutation in longitude and obliquity, to about 0.5" (Meeus ch. 22).
+pub(crate) fn nutation(c: f64) -> (f64, f64) {
+    let omega = 125.04452 - 1934.136261 * c;
+    let l_sun = 280.4665 + 36000.7698 * c;
+    let l_moon = 218.3165 + 481267.8813 * c;
+    let dpsi = -17.20 * sin_deg(omega) - 1.32 * sin_deg(2.0 * l_sun) - 0.23 * sin_deg(2.0 * l_moon)
+        + 0.21 * sin_deg(2.0 * omega);
+    let deps = 9.20 * cos_deg(omega) + 0.57 * cos_deg(2.0 * l_sun) + 0.10 * cos_deg(2.0 * l_moon)
+        - 0.09 * cos_deg(2.0 * omega);
+    (dpsi / 3600.0, deps / 3600.0)
+}
+
+/// Ecliptic (λ, β) to equatorial (α, δ) for obliquity `eps`.
+pub(crate) fn equatorial(lambda: f64, beta: f64, eps: f64) -> (f64, f64) {
+    let (sl, cl) = lambda.to_radians().sin_cos();
+    let (sb, cb) = beta.to_radians().sin_cos();
+    let (se, ce) = eps.to_radians().sin_cos();
+    let ra = (sl * ce - (sb / cb) * se).atan2(cl).to_degrees();
+    let dec = (sb * ce + cb * se * sl).asin().to_degrees();
+    (normalize_deg(ra), dec)
+}
+
+/// Greenwich mean sidereal time at a UT moment (Meeus 12.4).
+pub(crate) fn sidereal_time(ut: f64) -> f64 {
+    let d = ut + JD_OFFSET - 2_451_545.0;
+    let c = d / 36525.0;
+    normalize_deg(
+        280.46061837 + 360.98564736629 * d + 0.000387933 * c * c - c * c * c / 38_710_000.0,
+    )
+}
@@ -0,0 +1,128 @@
+//! Sunrise, sunset and twilight at the [`Context`] location.
+//!
+//! All functions take the local day `day` (per `time_zone`, else
+//! `timezone_offset_hours`, else local mean time) and return UT moments;
+//! convert them with [`TimeZone::to_local`](crate::core::zone::TimeZone::to_local)
+//! for local time.
+
+use super::{Location, horizon, time};
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, moment::Moment};
+
+/// Depth of twilight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Twilight {
+    /// Sun 6° below the horizon.
+    Civil,
+    /// Sun 12° below the horizon.
+    Nautical,
+    /// Sun 18° below the horizon.
+    Astronomical,
+}
+
+impl Twilight {
+    /// Altitude of the Sun's centre at which this twilight begins or ends.
+    #[inline]
+    pub fn altitude(self) -> f64 {
+        match self {
+            Twilight::Civil => -6.0,
+            Twilight::Nautical => -12.0,
+            Twilight::Astronomical => -18.0,
+        }
+    }
+}
+
+/// Moment the Sun crosses `altitude` on `day`, or why it does not.
+fn crossing(
+    day: EpochDay,
+    altitude: f64,
+    rising: bool,
+    ctx: Option<&Context>,
+) -> Result<Moment, CalError> {
+    let ctx = ctx.ok_or(CalError::NeedsContext)?;
+    let loc = Location::try_from(ctx)?.on_day(day, Some(ctx))?;
+    let dt = time::delta_t(Some(ctx), day.0 as f64);
+    horizon::sun_crossing(day.0, altitude, rising, dt, &loc)
+        .map(Moment)
+        .ok_or_else(|| {
+            if horizon::noon_altitude(day.0, dt, &loc) > altitude {
+                CalError::PolarDay
+            } else {
+                CalError::PolarNight
+            }
+        })
+}
+
+/// Moment (UT) the Sun's upper limb rises on local day `day`.
+///
+/// # Errors
+/// [`CalError::PolarDay`] or [`CalError::PolarNight`] if the Sun does not
+/// rise that day; [`CalError::NeedsContext`] without latitude and longitude.
+///
+/// # Example
+/// ```
+/// use calz::astro::daylight;
+/// use calz::calendars::gregorian::{self, Gregorian};
+/// use calz::core::error::CalError;
+/// use calz::core::traits::Calendar;
+/// use calz::Context;
+///
+/// // No sunrise at 80° N around the December solstice
+/// let solstice = gregorian::Date { year: 2024, month: 12, day: 21 };
+/// let day = Gregorian::to_epoch_day(&solstice, &Default::default(), None).unwrap();
+/// let ctx = Context {
+///     latitude_deg: Some(80.0),
+///     longitude_deg: Some(0.0),
+///     ..Default::default()
+/// };
+/// assert!(matches!(daylight::sunrise(day, Some(&ctx)), Err(CalError::PolarNight)));
+/// ```
+pub fn sunrise(day: EpochDay, ctx: Option<&Context>) -> Result<Moment, CalError> {
+    crossing(day, horizon::SUN_STANDARD_ALTITUDE, true, ctx)
+}
+
+/// Moment (UT) the Sun's upper limb sets on local day `day`.
+///
+/// # Errors
+/// As for [`sunrise`].
+///
+/// # Example
+/// ```
+/// use calz::astro::daylight;
+/// use calz::calendars::gregorian::{self, Gregorian};
+/// use calz::core::traits::Calendar;
+/// use calz::Context;
+///
+/// // Midsummer sunset at Greenwich, 21:21 BST (20:21 UT)
+/// let midsummer = gregorian::Date { year: 2024, month: 6, day: 21 };
+/// let day = Gregorian::to_epoch_day(&midsummer, &Default::default(), None).unwrap();
+/// let ctx = Context {
+///     latitude_deg: Some(51.4769),
+///     longitude_deg: Some(0.0),
+///     ..Default::default()
+/// };
+/// let sunset = daylight::sunset(day, Some(&ctx)).unwrap();
+/// assert_eq!(sunset.epoch_day(), day);
+/// let minutes = sunset.time_of_day() * 1440.0;
+/// assert!((minutes - (20.0 * 60.0 + 21.0)).abs() < 2.0);
+/// ```
+pub fn sunset(day: EpochDay, ctx: Option<&Context>) -> Result<Moment, CalError> {
+    crossing(day, horizon::SUN_STANDARD_ALTITUDE, false, ctx)
+}
+
+/// Moment (UT) morning `twilight` begins on local day `day`.
+///
+/// # Errors
+/// [`CalError::PolarDay`] if the Sun never gets that far below the horizon,
+/// [`CalError::PolarNight`] if it never climbs that high; otherwise as for
+/// [`sunrise`].
+pub fn dawn(day: EpochDay, twilight: Twilight, ctx: Option<&Context>) -> Result<Moment, CalError> {
+    crossing(day, twilight.altitude(), true, ctx)
+}
+
+/// Moment (UT) evening `twilight` ends on local day `day`.
+///
+/// # Errors
+/// As for [`dawn`].
+pub fn dusk(day: EpochDay, twilight: Twilight, ctx: Option<&Context>) -> Result<Moment, CalError> {
+    crossing(day, twilight.altitude(), false, ctx)
+}
@@ -0,0 +1,119 @@
+use super::{Location, coords, cos_deg, lunar, sin_deg, solar, time};
+
+/// Standard altitude of the Sun's upper limb at rise and set, with refraction.
+pub(crate) const SUN_STANDARD_ALTITUDE: f64 = -0.8333;
+
+/// Sampling step when scanning for horizon crossings (one hour).
+const STEP: f64 = 1.0 / 24.0;
+
+/// Altitude above the horizon of a body at (α, δ) for a UT moment.
+fn altitude(ut: f64, ra: f64, dec: f64, loc: &Location) -> f64 {
+    horizontal(ut, ra, dec, loc).0
+}
+
+/// Altitude and azimuth (measured westward from south) of a body at (α, δ)
+/// for a UT moment (Meeus 13).
+pub(crate) fn horizontal(ut: f64, ra: f64, dec: f64, loc: &Location) -> (f64, f64) {
+    let hour_angle = coords::sidereal_time(ut) + loc.longitude_deg - ra;
+    let lat = loc.latitude_deg;
+    let alt = (sin_deg(lat) * sin_deg(dec) + cos_deg(lat) * cos_deg(dec) * cos_deg(hour_angle))
+        .asin()
+        .to_degrees();
+    let az = sin_deg(hour_angle)
+        .atan2(cos_deg(hour_angle) * sin_deg(lat) - dec.to_radians().tan() * cos_deg(lat))
+        .to_degrees();
+    (alt, az)
+}
+
+/// Altitude of the Sun's centre.
+fn sun_altitude(ut: f64, dt: f64, loc: &Location) -> f64 {
+    let (ra, dec) = solar::equatorial(time::julian_centuries(ut + dt));
+    altitude(ut, ra, dec, loc)
+}
+
+/// Altitude of the Moon's centre minus its standard rise/set altitude,
+/// which depends on the horizontal parallax (Meeus 15).
+fn moon_height(ut: f64, dt: f64, loc: &Location) -> f64 {
+    let (ra, dec, distance) = lunar::equatorial(time::julian_centuries(ut + dt));
+    let parallax = (6378.14 / distance).asin().to_degrees();
+    altitude(ut, ra, dec, loc) - (0.7275 * parallax - 0.5667)
+}
+
+/// First moment in `[start, end)` where `f` goes from positive to negative.
+fn setting(f: impl Fn(f64) -> f64, start: f64, end: f64) -> Option<f64> {
+    let mut t0 = start;
+    let mut f0 = f(t0);
+    while t0 < end {
+        let t1 = (t0 + STEP).min(end);
+        let f1 = f(t1);
+        if f0 > 0.0 && f1 <= 0.0 {
+            // Bisect down to about a second
+            let (mut lo, mut hi) = (t0, t1);
+            while hi - lo > 1e-5 {
+                let mid = 0.5 * (lo + hi);
+                if f(mid) > 0.0 {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            return Some(0.5 * (lo + hi));
+        }
+        t0 = t1;
+        f0 = f1;
+    }
+    None
+}
+
+/// UT moment of sunset on local day `day` at `loc`, if the Sun sets that day.
+pub(crate) fn sunset(day: i64, dt: f64, loc: &Location) -> Option<f64> {
+    sun_crossing(day, SUN_STANDARD_ALTITUDE, false, dt, loc)
+}
+
+/// UT moment on local day `day` at which the Sun climbs through `depression`
+/// degrees below the horizon, if it does that day.
+#[inline]
+pub(crate) fn dawn(day: i64, depression: f64, dt: f64, loc: &Location) -> Option<f64> {
+    sun_crossing(day, -depression, true, dt, loc)
+}
+
+/// UT moment on local day `day` at which the Sun's centre rises (or sets)
+/// through `altitude`, if it does that day.
+pub(crate) fn sun_crossing(
+    day: i64,
+    altitude: f64,
+    rising: bool,
+    dt: f64,
+    loc: &Location,
+) -> Option<f64> {
+    let start = day as f64 - loc.zone_hours / 24.0;
+    let sign = if rising { -1.0 } else { 1.0 };
+    setting(
+        |t| sign * (sun_altitude(t, dt, loc) - altitude),
+        start,
+        start + 1.0,
+    )
+}
+
+/// Altitude of the Sun's centre at local noon of `day`.
+pub(crate) fn noon_altitude(day: i64, dt: f64, loc: &Location) -> f64 {
+    let noon = day as f64 + 0.5 - loc.longitude_deg / 360.0;
+    sun_altitude(noon, dt, loc)
+}
+
+/// UT moment of the first moonset within a day after `ut`.
+pub(crate) fn moonset_after(ut: f64, dt: f64, loc: &Location) -> Option<f64> {
+    setting(|t| moon_height(t, dt, loc), ut, ut + 1.0)
+}
+
+/// Time in days from sunset to moonset on local day `day`.
+///
+/// Zero if the Moon is already down at sunset, one day if it does not set
+/// within a day; `None` if the Sun does not set.
+pub(crate) fn moonlag(day: i64, dt: f64, loc: &Location) -> Option<f64> {
+    let set = sunset(day, dt, loc)?;
+    if moon_height(set, dt, loc) <= 0.0 {
+        return Some(0.0);
+    }
+    Some(moonset_after(set, dt, loc).map_or(1.0, |moonset| moonset - set))
+}
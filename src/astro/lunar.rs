@@ -0,0 +1,429 @@
+//! Position and phases of the Moon.
+
+use super::{coords, cos_deg, normalize_deg, poly, sin_deg, solar, time};
+use crate::core::{context::Context, epoch::EpochDay, moment::Moment};
+
+/// Mean synodic month in days.
+pub(crate) const MEAN_SYNODIC_MONTH: f64 = 29.530_588_861;
+
+/// JDE of the mean new moon of 2000-01-06, lunation `k = 0` in Meeus.
+const NEW_MOON_0: f64 = 2_451_550.097_66;
+
+/// Geocentric position of the Moon.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Position {
+    /// Apparent ecliptic longitude in degrees.
+    pub longitude: f64,
+    /// Ecliptic latitude in degrees.
+    pub latitude: f64,
+    /// Distance from the Earth's centre in km.
+    pub distance_km: f64,
+}
+
+// Periodic terms for longitude and distance (Meeus table 47.A):
+// multiples of D, M, M', F; Σl in 1e-6 degrees; Σr in 1e-3 km.
+#[rustfmt::skip]
+const LON_DIST: [(i8, i8, i8, i8, i32, i32); 60] = [
+    (0, 0, 1, 0, 6288774, -20905355), (2, 0, -1, 0, 1274027, -3699111),
+    (2, 0, 0, 0, 658314, -2955968), (0, 0, 2, 0, 213618, -569925),
+    (0, 1, 0, 0, -185116, 48888), (0, 0, 0, 2, -114332, -3149),
+    (2, 0, -2, 0, 58793, 246158), (2, -1, -1, 0, 57066, -152138),
+    (2, 0, 1, 0, 53322, -170733), (2, -1, 0, 0, 45758, -204586),
+    (0, 1, -1, 0, -40923, -129620), (1, 0, 0, 0, -34720, 108743),
+    (0, 1, 1, 0, -30383, 104755), (2, 0, 0, -2, 15327, 10321),
+    (0, 0, 1, 2, -12528, 0), (0, 0, 1, -2, 10980, 79661),
+    (4, 0, -1, 0, 10675, -34782), (0, 0, 3, 0, 10034, -23210),
+    (4, 0, -2, 0, 8548, -21636), (2, 1, -1, 0, -7888, 24208),
+    (2, 1, 0, 0, -6766, 30824), (1, 0, -1, 0, -5163, -8379),
+    (1, 1, 0, 0, 4987, -16675), (2, -1, 1, 0, 4036, -12831),
+    (2, 0, 2, 0, 3994, -10445), (4, 0, 0, 0, 3861, -11650),
+    (2, 0, -3, 0, 3665, 14403), (0, 1, -2, 0, -2689, -7003),
+    (2, 0, -1, 2, -2602, 0), (2, -1, -2, 0, 2390, 10056),
+    (1, 0, 1, 0, -2348, 6322), (2, -2, 0, 0, 2236, -9884),
+    (0, 1, 2, 0, -2120, 5751), (0, 2, 0, 0, -2069, 0),
+    (2, -2, -1, 0, 2048, -4950), (2, 0, 1, -2, -1773, 4130),
+    (2, 0, 0, 2, -1595, 0), (4, -1, -1, 0, 1215, -3958),
+    (0, 0, 2, 2, -1110, 0), (3, 0, -1, 0, -892, 3258),
+    (2, 1, 1, 0, -810, 2616), (4, -1, -2, 0, 759, -1897),
+    (0, 2, -1, 0, -713, -2117), (2, 2, -1, 0, -700, 2354),
+    (2, 1, -2, 0, 691, 0), (2, -1, 0, -2, 596, 0),
+    (4, 0, 1, 0, 549, -1423), (0, 0, 4, 0, 537, -1117),
+    (4, -1, 0, 0, 520, -1571), (1, 0, -2, 0, -487, -1739),
+    (2, 1, 0, -2, -399, 0), (0, 0, 2, -2, -381, -4421),
+    (1, 1, 1, 0, 351, 0), (3, 0, -2, 0, -340, 0),
+    (4, 0, -3, 0, 330, 0), (2, -1, 2, 0, 327, 0),
+    (0, 2, 1, 0, -323, 1165), (1, 1, -1, 0, 299, 0),
+    (2, 0, 3, 0, 294, 0), (2, 0, -1, -2, 0, 8752),
+];
+
+// Periodic terms for latitude (Meeus table 47.B): D, M, M', F; Σb in 1e-6 degrees.
+#[rustfmt::skip]
+const LAT: [(i8, i8, i8, i8, i32); 60] = [
+    (0, 0, 0, 1, 5128122), (0, 0, 1, 1, 280602), (0, 0, 1, -1, 277693),
+    (2, 0, 0, -1, 173237), (2, 0, -1, 1, 55413), (2, 0, -1, -1, 46271),
+    (2, 0, 0, 1, 32573), (0, 0, 2, 1, 17198), (2, 0, 1, -1, 9266),
+    (0, 0, 2, -1, 8822), (2, -1, 0, -1, 8216), (2, 0, -2, -1, 4324),
+    (2, 0, 1, 1, 4200), (2, 1, 0, -1, -3359), (2, -1, -1, 1, 2463),
+    (2, -1, 0, 1, 2211), (2, -1, -1, -1, 2065), (0, 1, -1, -1, -1870),
+    (4, 0, -1, -1, 1828), (0, 1, 0, 1, -1794), (0, 0, 0, 3, -1749),
+    (0, 1, -1, 1, -1565), (1, 0, 0, 1, -1491), (0, 1, 1, 1, -1475),
+    (0, 1, 1, -1, -1410), (0, 1, 0, -1, -1344), (1, 0, 0, -1, -1335),
+    (0, 0, 3, 1, 1107), (4, 0, 0, -1, 1021), (4, 0, -1, 1, 833),
+    (0, 0, 1, -3, 777), (4, 0, -2, 1, 671), (2, 0, 0, -3, 607),
+    (2, 0, 2, -1, 596), (2, -1, 1, -1, 491), (2, 0, -2, 1, -451),
+    (0, 0, 3, -1, 439), (2, 0, 2, 1, 422), (2, 0, -3, -1, 421),
+    (2, 1, -1, 1, -366), (2, 1, 0, 1, -351), (4, 0, 0, 1, 331),
+    (2, -1, 1, 1, 315), (2, -2, 0, -1, 302), (0, 0, 1, 3, -283),
+    (2, 1, 1, -1, -229), (1, 1, 0, -1, 223), (1, 1, 0, 1, 223),
+    (0, 1, -2, -1, -220), (2, 1, -1, -1, -220), (1, 0, 1, 1, -185),
+    (2, -1, -2, -1, 181), (0, 1, 2, 1, -177), (4, -2, -1, -1, 176),
+    (4, -1, -1, -1, 166), (1, 0, 1, -1, -164), (4, 0, 1, -1, 132),
+    (1, 0, -1, -1, -119), (4, -1, 0, -1, 115), (2, -2, 0, 1, 107),
+];
+
+/// Geocentric position of the Moon, to about 10" (Meeus ch. 47).
+///
+/// `c` is Julian centuries of Terrestrial Time since J2000.0.
+pub(crate) fn position(c: f64) -> Position {
+    let l = poly(
+        c,
+        &[
+            218.3164477,
+            481267.88123421,
+            -0.0015786,
+            1.0 / 538_841.0,
+            -1.0 / 65_194_000.0,
+        ],
+    );
+    let d = poly(
+        c,
+        &[
+            297.8501921,
+            445267.1114034,
+            -0.0018819,
+            1.0 / 545_868.0,
+            -1.0 / 113_065_000.0,
+        ],
+    );
+    let m = poly(
+        c,
+        &[357.5291092, 35999.0502909, -0.0001536, 1.0 / 24_490_000.0],
+    );
+    let mp = poly(
+        c,
+        &[
+            134.9633964,
+            477198.8675055,
+            0.0087414,
+            1.0 / 69_699.0,
+            -1.0 / 14_712_000.0,
+        ],
+    );
+    let f = poly(
+        c,
+        &[
+            93.2720950,
+            483202.0175233,
+            -0.0036539,
+            -1.0 / 3_526_000.0,
+            1.0 / 863_310_000.0,
+        ],
+    );
+    let a1 = 119.75 + 131.849 * c;
+    let a2 = 53.09 + 479264.290 * c;
+    let a3 = 313.45 + 481266.484 * c;
+    // Eccentricity of the Earth's orbit scales terms involving M
+    let e = poly(c, &[1.0, -0.002516, -0.0000074]);
+    let ecc = |k: i8| e.powi(k.unsigned_abs() as i32);
+
+    let arg = |kd: i8, km: i8, kmp: i8, kf: i8| {
+        kd as f64 * d + km as f64 * m + kmp as f64 * mp + kf as f64 * f
+    };
+
+    let (mut sl, mut sr) = (0.0, 0.0);
+    for (kd, km, kmp, kf, cl, cr) in LON_DIST {
+        let x = arg(kd, km, kmp, kf).to_radians();
+        sl += cl as f64 * ecc(km) * x.sin();
+        sr += cr as f64 * ecc(km) * x.cos();
+    }
+    let mut sb = 0.0;
+    for (kd, km, kmp, kf, cb) in LAT {
+        sb += cb as f64 * ecc(km) * sin_deg(arg(kd, km, kmp, kf));
+    }
+
+    sl += 3958.0 * sin_deg(a1) + 1962.0 * sin_deg(l - f) + 318.0 * sin_deg(a2);
+    sb += -2235.0 * sin_deg(l)
+        + 382.0 * sin_deg(a3)
+        + 175.0 * sin_deg(a1 - f)
+        + 175.0 * sin_deg(a1 + f)
+        + 127.0 * sin_deg(l - mp)
+        - 115.0 * sin_deg(l + mp);
+
+    let (dpsi, _) = coords::nutation(c);
+    Position {
+        longitude: normalize_deg(l + sl / 1e6 + dpsi),
+        latitude: sb / 1e6,
+        distance_km: 385_000.56 + sr / 1e3,
+    }
+}
+
+/// Apparent right ascension and declination of the Moon, with its distance.
+pub(crate) fn equatorial(c: f64) -> (f64, f64, f64) {
+    let pos = position(c);
+    let (_, deps) = coords::nutation(c);
+    let (ra, dec) = coords::equatorial(pos.longitude, pos.latitude, coords::obliquity(c) + deps);
+    (ra, dec, pos.distance_km)
+}
+
+/// Lunar phase at a UT moment: elongation in longitude of the Moon from the
+/// Sun, in `[0, 360)`. `0` is new moon, `180` full moon.
+pub(crate) fn phase(ut: f64, dt: f64) -> f64 {
+    let c = time::julian_centuries(ut + dt);
+    normalize_deg(position(c).longitude - solar::apparent_longitude(c))
+}
+
+/// The four principal phases of the Moon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+}
+
+impl Phase {
+    /// Fraction of a lunation at which the phase occurs.
+    #[inline]
+    fn fraction(self) -> f64 {
+        match self {
+            Phase::NewMoon => 0.0,
+            Phase::FirstQuarter => 0.25,
+            Phase::FullMoon => 0.5,
+            Phase::LastQuarter => 0.75,
+        }
+    }
+}
+
+/// Moment (TT) of the `k`-th new moon after the one of 2000-01-06 (Meeus ch. 49).
+#[inline]
+pub(crate) fn new_moon(k: i64) -> f64 {
+    phase_moment(k, Phase::NewMoon)
+}
+
+/// Moment (TT) of `phase` in lunation `k`, counted from the new moon of
+/// 2000-01-06 (Meeus ch. 49).
+pub(crate) fn phase_moment(k: i64, phase: Phase) -> f64 {
+    let k = k as f64 + phase.fraction();
+    let c = k / 1236.85;
+    let jde = poly(c, &[0.0, 0.0, 0.00015437, -0.000000150, 0.00000000073])
+        + NEW_MOON_0
+        + MEAN_SYNODIC_MONTH * k;
+
+    let e = poly(c, &[1.0, -0.002516, -0.0000074]);
+    let m = poly(c, &[0.0, 0.0, -0.0000014, -0.00000011]) + 2.5534 + 29.10535670 * k;
+    let mp =
+        poly(c, &[0.0, 0.0, 0.0107582, 0.00001238, -0.000000058]) + 201.5643 + 385.81693528 * k;
+    let f =
+        poly(c, &[0.0, 0.0, -0.0016118, -0.00000227, 0.000000011]) + 160.7108 + 390.67050284 * k;
+    let omega = poly(c, &[124.7746, 0.0, 0.0020672, 0.00000215]) - 1.56375588 * k;
+
+    let correction = match phase {
+        Phase::NewMoon => new_moon_correction(e, m, mp, f, omega),
+        Phase::FullMoon => full_moon_correction(e, m, mp, f, omega),
+        Phase::FirstQuarter => quarter_correction(e, m, mp, f, omega) + quarter_w(e, m, mp, f),
+        Phase::LastQuarter => quarter_correction(e, m, mp, f, omega) - quarter_w(e, m, mp, f),
+    };
+
+    jde + correction + planetary_correction(k, c) - time::JD_OFFSET
+}
+
+#[rustfmt::skip]
+fn new_moon_correction(e: f64, m: f64, mp: f64, f: f64, omega: f64) -> f64 {
+    -0.40720 * sin_deg(mp)
+        + 0.17241 * e * sin_deg(m)
+        + 0.01608 * sin_deg(2.0 * mp)
+        + 0.01039 * sin_deg(2.0 * f)
+        + 0.00739 * e * sin_deg(mp - m)
+        - 0.00514 * e * sin_deg(mp + m)
+        + 0.00208 * e * e * sin_deg(2.0 * m)
+        - 0.00111 * sin_deg(mp - 2.0 * f)
+        - 0.00057 * sin_deg(mp + 2.0 * f)
+        + 0.00056 * e * sin_deg(2.0 * mp + m)
+        - 0.00042 * sin_deg(3.0 * mp)
+        + 0.00042 * e * sin_deg(m + 2.0 * f)
+        + 0.00038 * e * sin_deg(m - 2.0 * f)
+        - 0.00024 * e * sin_deg(2.0 * mp - m)
+        - 0.00017 * sin_deg(omega)
+        - 0.00007 * sin_deg(mp + 2.0 * m)
+        + 0.00004 * sin_deg(2.0 * mp - 2.0 * f)
+        + 0.00004 * sin_deg(3.0 * m)
+        + 0.00003 * sin_deg(mp + m - 2.0 * f)
+        + 0.00003 * sin_deg(2.0 * mp + 2.0 * f)
+        - 0.00003 * sin_deg(mp + m + 2.0 * f)
+        + 0.00003 * sin_deg(mp - m + 2.0 * f)
+        - 0.00002 * sin_deg(mp - m - 2.0 * f)
+        - 0.00002 * sin_deg(3.0 * mp + m)
+        + 0.00002 * sin_deg(4.0 * mp)
+}
+
+#[rustfmt::skip]
+fn full_moon_correction(e: f64, m: f64, mp: f64, f: f64, omega: f64) -> f64 {
+    -0.40614 * sin_deg(mp)
+        + 0.17302 * e * sin_deg(m)
+        + 0.01614 * sin_deg(2.0 * mp)
+        + 0.01043 * sin_deg(2.0 * f)
+        + 0.00734 * e * sin_deg(mp - m)
+        - 0.00515 * e * sin_deg(mp + m)
+        + 0.00209 * e * e * sin_deg(2.0 * m)
+        - 0.00111 * sin_deg(mp - 2.0 * f)
+        - 0.00057 * sin_deg(mp + 2.0 * f)
+        + 0.00056 * e * sin_deg(2.0 * mp + m)
+        - 0.00042 * sin_deg(3.0 * mp)
+        + 0.00042 * e * sin_deg(m + 2.0 * f)
+        + 0.00038 * e * sin_deg(m - 2.0 * f)
+        - 0.00024 * e * sin_deg(2.0 * mp - m)
+        - 0.00017 * sin_deg(omega)
+        - 0.00007 * sin_deg(mp + 2.0 * m)
+        + 0.00004 * sin_deg(2.0 * mp - 2.0 * f)
+        + 0.00004 * sin_deg(3.0 * m)
+        + 0.00003 * sin_deg(mp + m - 2.0 * f)
+        + 0.00003 * sin_deg(2.0 * mp + 2.0 * f)
+        - 0.00003 * sin_deg(mp + m + 2.0 * f)
+        + 0.00003 * sin_deg(mp - m + 2.0 * f)
+        - 0.00002 * sin_deg(mp - m - 2.0 * f)
+        - 0.00002 * sin_deg(3.0 * mp + m)
+        + 0.00002 * sin_deg(4.0 * mp)
+}
+
+#[rustfmt::skip]
+fn quarter_correction(e: f64, m: f64, mp: f64, f: f64, omega: f64) -> f64 {
+    -0.62801 * sin_deg(mp)
+        + 0.17172 * e * sin_deg(m)
+        - 0.01183 * e * sin_deg(mp + m)
+        + 0.00862 * sin_deg(2.0 * mp)
+        + 0.00804 * sin_deg(2.0 * f)
+        + 0.00454 * e * sin_deg(mp - m)
+        + 0.00204 * e * e * sin_deg(2.0 * m)
+        - 0.00180 * sin_deg(mp - 2.0 * f)
+        - 0.00070 * sin_deg(mp + 2.0 * f)
+        - 0.00040 * sin_deg(3.0 * mp)
+        - 0.00034 * e * sin_deg(2.0 * mp - m)
+        + 0.00032 * e * sin_deg(m + 2.0 * f)
+        + 0.00032 * e * sin_deg(m - 2.0 * f)
+        - 0.00028 * e * e * sin_deg(mp + 2.0 * m)
+        + 0.00027 * e * sin_deg(2.0 * mp + m)
+        - 0.00017 * sin_deg(omega)
+        - 0.00005 * sin_deg(mp - m - 2.0 * f)
+        + 0.00004 * sin_deg(2.0 * mp + 2.0 * f)
+        - 0.00004 * sin_deg(mp + m + 2.0 * f)
+        + 0.00004 * sin_deg(mp - 2.0 * m)
+        + 0.00003 * sin_deg(mp + m - 2.0 * f)
+        + 0.00003 * sin_deg(3.0 * m)
+        + 0.00002 * sin_deg(2.0 * mp - 2.0 * f)
+        + 0.00002 * sin_deg(mp - m + 2.0 * f)
+        - 0.00002 * sin_deg(3.0 * mp + m)
+}
+
+/// Extra correction W, added at first quarter and subtracted at last quarter.
+fn quarter_w(e: f64, m: f64, mp: f64, f: f64) -> f64 {
+    0.00306 - 0.00038 * e * cos_deg(m) + 0.00026 * cos_deg(mp) - 0.00002 * cos_deg(mp - m)
+        + 0.00002 * cos_deg(mp + m)
+        + 0.00002 * cos_deg(2.0 * f)
+}
+
+/// Additional corrections from the planetary arguments A1..A14 (Meeus 49).
+fn planetary_correction(k: f64, c: f64) -> f64 {
+    #[rustfmt::skip]
+    const TERMS: [(f64, f64, f64); 14] = [
+        (299.77, 0.107408, 0.000325), (251.88, 0.016321, 0.000165),
+        (251.83, 26.651886, 0.000164), (349.42, 36.412478, 0.000126),
+        (84.66, 18.206239, 0.000110), (141.74, 53.303771, 0.000062),
+        (207.14, 2.453732, 0.000060), (154.84, 7.306860, 0.000056),
+        (34.52, 27.261239, 0.000047), (207.19, 0.121824, 0.000042),
+        (291.34, 1.844379, 0.000040), (161.72, 24.198154, 0.000037),
+        (239.56, 25.513099, 0.000035), (331.55, 3.592518, 0.000023),
+    ];
+    TERMS
+        .iter()
+        .enumerate()
+        .map(|(i, &(a, b, amp))| {
+            // A1 carries a quadratic term
+            let quad = if i == 0 { -0.009173 * c * c } else { 0.0 };
+            amp * sin_deg(a + b * k + quad)
+        })
+        .sum()
+}
+
+/// Lunation of the last `phase` at or before the TT moment `tt`.
+fn lunation_at_or_before(tt: f64, phase: Phase) -> i64 {
+    let mut k = ((tt + time::JD_OFFSET - NEW_MOON_0) / MEAN_SYNODIC_MONTH - phase.fraction())
+        .floor() as i64;
+    while phase_moment(k, phase) > tt {
+        k -= 1;
+    }
+    while phase_moment(k + 1, phase) <= tt {
+        k += 1;
+    }
+    k
+}
+
+/// Moment (UT) of the last new moon at or before the UT moment `ut`.
+pub(crate) fn new_moon_at_or_before(ut: f64, dt: f64) -> f64 {
+    new_moon(lunation_at_or_before(ut + dt, Phase::NewMoon)) - dt
+}
+
+/// Moment (UT) of the `n`-th new moon, counted from the new moon of
+/// 2000-01-06 (`n = 0`); negative `n` count backwards.
+pub fn nth_new_moon(n: i64, ctx: Option<&Context>) -> Moment {
+    Moment::from_tt(Moment(new_moon(n)), ctx)
+}
+
+/// Moment (UT) of the first `phase` at or after the start of `day`.
+pub fn next_phase(phase: Phase, day: EpochDay, ctx: Option<&Context>) -> Moment {
+    let dt = time::delta_t(ctx, day.0 as f64);
+    let tt = day.0 as f64 + dt;
+    let mut k = lunation_at_or_before(tt, phase);
+    if phase_moment(k, phase) < tt {
+        k += 1;
+    }
+    Moment(phase_moment(k, phase) - dt)
+}
+
+/// Moment (UT) of the last `phase` before the start of `day`.
+pub fn previous_phase(phase: Phase, day: EpochDay, ctx: Option<&Context>) -> Moment {
+    let dt = time::delta_t(ctx, day.0 as f64);
+    let tt = day.0 as f64 + dt;
+    let mut k = lunation_at_or_before(tt, phase);
+    if phase_moment(k, phase) >= tt {
+        k -= 1;
+    }
+    Moment(phase_moment(k, phase) - dt)
+}
+
+/// Moment (UT) of the first new moon at or after the start of `day`.
+///
+/// # Example
+/// ```
+/// use calz::astro::lunar;
+/// use calz::calendars::gregorian::{self, Gregorian};
+/// use calz::core::traits::Calendar;
+///
+/// // The new moon of the 2024-04-08 total solar eclipse, at 18:21 UT
+/// let april = gregorian::Date { year: 2024, month: 4, day: 1 };
+/// let day = Gregorian::to_epoch_day(&april, &Default::default(), None).unwrap();
+/// let new_moon = lunar::next_new_moon(day, None);
+/// let date = Gregorian::from_epoch_day(new_moon.epoch_day(), &Default::default(), None).unwrap();
+/// assert_eq!(date, gregorian::Date { year: 2024, month: 4, day: 8 });
+/// let minutes = new_moon.time_of_day() * 1440.0;
+/// assert!((minutes - (18.0 * 60.0 + 21.0)).abs() < 2.0);
+/// ```
+#[inline]
+pub fn next_new_moon(day: EpochDay, ctx: Option<&Context>) -> Moment {
+    next_phase(Phase::NewMoon, day, ctx)
+}
+
+/// Moment (UT) of the last new moon before the start of `day`.
+#[inline]
+pub fn previous_new_moon(day: EpochDay, ctx: Option<&Context>) -> Moment {
+    previous_phase(Phase::NewMoon, day, ctx)
+}
@@ -0,0 +1,127 @@
+//! Astronomical algorithms used by observational calendars.
+//!
+//! Public functions take and return [`Moment`]s in Universal Time; internally
+//! moments are plain `f64` days on the same scale, in Universal Time unless a
+//! name says otherwise. Angles are in degrees.
+//! Most series follow Jean Meeus, _Astronomical Algorithms_ (2nd ed.).
+
+pub(crate) mod coords;
+pub mod daylight;
+pub(crate) mod horizon;
+pub mod lunar;
+pub mod solar;
+pub mod time;
+pub mod visibility;
+
+use crate::core::{
+    context::Context, epoch::EpochDay, error::CalError, moment::Moment, zone::TimeZone,
+};
+
+/// An observer's position on Earth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    /// Geographic latitude in degrees, north positive.
+    pub latitude_deg: f64,
+    /// Geographic longitude in degrees, east positive.
+    pub longitude_deg: f64,
+    /// Offset of the local day from UT in hours, east positive.
+    pub zone_hours: f64,
+}
+
+impl Location {
+    /// Location whose local day follows local mean time.
+    pub fn new(latitude_deg: f64, longitude_deg: f64) -> Self {
+        Self {
+            latitude_deg,
+            longitude_deg,
+            zone_hours: longitude_deg / 15.0,
+        }
+    }
+
+    /// Applies the coordinates and timezone set in `ctx` on top of `default`.
+    ///
+    /// When only the coordinates are overridden, the local day follows local
+    /// mean time at the new longitude. `time_zone` depends on the date, so
+    /// callers that know the day apply it afterwards.
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidContext`] if the coordinates are out of range.
+    pub fn from_context(ctx: Option<&Context>, default: Location) -> Result<Self, CalError> {
+        let Some(ctx) = ctx else {
+            return Ok(default);
+        };
+        ctx.validate().map_err(|_| CalError::InvalidContext)?;
+
+        let mut loc = default;
+        if let Some(lat) = ctx.latitude_deg {
+            loc.latitude_deg = lat;
+        }
+        if let Some(lon) = ctx.longitude_deg {
+            loc = Location {
+                zone_hours: lon / 15.0,
+                longitude_deg: lon,
+                ..loc
+            };
+        }
+        if let Some(zone) = ctx.timezone_offset_hours {
+            loc.zone_hours = zone;
+        }
+        Ok(loc)
+    }
+
+    /// This location with the local day `day` taken in the context's
+    /// `time_zone`, if set, at the offset in force at its start.
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidContext`] if `time_zone` cannot be resolved.
+    pub(crate) fn on_day(self, day: EpochDay, ctx: Option<&Context>) -> Result<Self, CalError> {
+        let Some(zone) = TimeZone::from_context(ctx.filter(|c| c.time_zone.is_some()))? else {
+            return Ok(self);
+        };
+        let start = zone.to_utc(Moment::from_epoch_day(day));
+        Ok(Location {
+            zone_hours: zone.offset_hours(start),
+            ..self
+        })
+    }
+}
+
+/// Observer taken entirely from the context.
+///
+/// The local day follows `timezone_offset_hours` if set, local mean time
+/// otherwise; `time_zone` is applied once the day is known.
+impl TryFrom<&Context> for Location {
+    type Error = CalError;
+
+    /// # Errors
+    /// Returns [`CalError::NeedsContext`] if latitude or longitude is missing,
+    /// [`CalError::InvalidContext`] if they are out of range.
+    fn try_from(ctx: &Context) -> Result<Self, CalError> {
+        let (Some(lat), Some(lon)) = (ctx.latitude_deg, ctx.longitude_deg) else {
+            return Err(CalError::NeedsContext);
+        };
+        Location::from_context(Some(ctx), Location::new(lat, lon))
+    }
+}
+
+#[inline]
+fn sin_deg(x: f64) -> f64 {
+    x.to_radians().sin()
+}
+
+#[inline]
+fn cos_deg(x: f64) -> f64 {
+    x.to_radians().cos()
+}
+
+/// Reduces an angle to `[0, 360)`.
+#[inline]
+fn normalize_deg(x: f64) -> f64 {
+    x.rem_euclid(360.0)
+}
+
+/// Evaluates a polynomial with coefficients in ascending order.
+#[inline]
+fn poly(x: f64, coeffs: &[f64]) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
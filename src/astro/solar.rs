@@ -0,0 +1,176 @@
+//! Position of the Sun, seasons and the 24 solar terms.
+
+use super::{coords, cos_deg, normalize_deg, sin_deg, time};
+use crate::calendars::gregorian::Gregorian;
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, moment::Moment};
+
+/// Mean tropical year in days.
+const MEAN_TROPICAL_YEAR: f64 = 365.242_189;
+
+/// Equinoxes and solstices, named after their month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    MarchEquinox,
+    JuneSolstice,
+    SeptemberEquinox,
+    DecemberSolstice,
+}
+
+impl Season {
+    /// Apparent solar longitude at which the season begins.
+    #[inline]
+    pub fn longitude(self) -> f64 {
+        match self {
+            Season::MarchEquinox => 0.0,
+            Season::JuneSolstice => 90.0,
+            Season::SeptemberEquinox => 180.0,
+            Season::DecemberSolstice => 270.0,
+        }
+    }
+}
+
+/// Apparent geocentric longitude of the Sun, to a few arcseconds.
+///
+/// `c` is Julian centuries of Terrestrial Time since J2000.0. The series is
+/// that of Bretagnon & Simon, as given in _Calendrical Calculations_ (ch. 14).
+pub(crate) fn apparent_longitude(c: f64) -> f64 {
+    #[rustfmt::skip]
+    const TERMS: [(f64, f64, f64); 49] = [
+        (403406.0, 270.54861, 0.9287892), (195207.0, 340.19128, 35999.1376958),
+        (119433.0, 63.91854, 35999.4089666), (112392.0, 331.2622, 35998.7287385),
+        (3891.0, 317.843, 71998.20261), (2819.0, 86.631, 71998.4403),
+        (1721.0, 240.052, 36000.35726), (660.0, 310.26, 71997.4812),
+        (350.0, 247.23, 32964.4678), (334.0, 260.87, -19.441),
+        (314.0, 297.82, 445267.1117), (268.0, 343.14, 45036.884),
+        (242.0, 166.79, 3.1008), (234.0, 81.53, 22518.4434),
+        (158.0, 3.5, -19.9739), (132.0, 132.75, 65928.9345),
+        (129.0, 182.95, 9038.0293), (114.0, 162.03, 3034.7684),
+        (99.0, 29.8, 33718.148), (93.0, 266.4, 3034.448),
+        (86.0, 249.2, -2280.773), (78.0, 157.6, 29929.992),
+        (72.0, 257.8, 31556.493), (68.0, 185.1, 149.588),
+        (64.0, 69.9, 9037.75), (46.0, 8.0, 107997.405),
+        (38.0, 197.1, -4444.176), (37.0, 250.4, 151.771),
+        (32.0, 65.3, 67555.316), (29.0, 162.7, 31556.08),
+        (28.0, 341.5, -4561.54), (27.0, 291.6, 107996.706),
+        (27.0, 98.5, 1221.655), (25.0, 146.7, 62894.167),
+        (24.0, 110.0, 31437.369), (21.0, 5.2, 14578.298),
+        (21.0, 342.6, -31931.757), (20.0, 230.9, 34777.243),
+        (18.0, 256.1, 1221.999), (17.0, 45.3, 62894.511),
+        (14.0, 242.9, -4442.039), (13.0, 115.2, 107997.909),
+        (13.0, 151.8, 119.066), (13.0, 285.3, 16859.071),
+        (12.0, 53.3, -4.578), (10.0, 126.6, 26895.292),
+        (10.0, 205.7, -39.127), (10.0, 85.9, 12297.536),
+        (10.0, 146.1, 90073.778),
+    ];
+    let periodic: f64 = TERMS.iter().map(|&(x, y, z)| x * sin_deg(y + z * c)).sum();
+    let aberration = 0.0000974 * cos_deg(177.63 + 35999.01848 * c) - 0.005575;
+    let (dpsi, _) = coords::nutation(c);
+    normalize_deg(
+        282.7771834
+            + 36000.76953744 * c
+            + periodic * 0.000_005_729_577_951_308_232
+            + aberration
+            + dpsi,
+    )
+}
+
+/// Apparent right ascension and declination of the Sun.
+pub(crate) fn equatorial(c: f64) -> (f64, f64) {
+    let (_, deps) = coords::nutation(c);
+    coords::equatorial(apparent_longitude(c), 0.0, coords::obliquity(c) + deps)
+}
+
+/// Apparent solar longitude at a UT moment.
+pub fn longitude(ut: Moment, ctx: Option<&Context>) -> f64 {
+    apparent_longitude(time::julian_centuries(ut.to_tt(ctx).0))
+}
+
+/// Moment (UT) at which the Sun first reaches apparent longitude `lambda`
+/// at or after the start of `day`.
+pub fn longitude_on_or_after(lambda: f64, day: EpochDay, ctx: Option<&Context>) -> Moment {
+    let dt = time::delta_t(ctx, day.0 as f64);
+    let longitude_tt = |tt: f64| apparent_longitude(time::julian_centuries(tt));
+
+    // Estimate from the mean motion, then bisect within a few days
+    let start = day.0 as f64 + dt;
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let estimate = start + rate * normalize_deg(lambda - longitude_tt(start));
+    let (mut lo, mut hi) = ((estimate - 5.0).max(start), estimate + 5.0);
+    while hi - lo > 1e-6 {
+        let mid = 0.5 * (lo + hi);
+        if normalize_deg(longitude_tt(mid) - lambda) < 180.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Moment(0.5 * (lo + hi) - dt)
+}
+
+/// Moment (UT) of an equinox or solstice in Gregorian year `year`.
+///
+/// # Example
+/// ```
+/// use calz::astro::solar::{self, Season};
+/// use calz::calendars::gregorian::{self, Gregorian};
+/// use calz::core::traits::Calendar;
+///
+/// // The March equinox of 2024 fell on 03-20 at 03:06 UT
+/// let equinox = solar::season(2024, Season::MarchEquinox, None);
+/// let date = Gregorian::from_epoch_day(equinox.epoch_day(), &Default::default(), None).unwrap();
+/// assert_eq!(date, gregorian::Date { year: 2024, month: 3, day: 20 });
+/// let minutes = equinox.time_of_day() * 1440.0;
+/// assert!((minutes - (3.0 * 60.0 + 6.0)).abs() < 2.0);
+/// ```
+pub fn season(year: i32, season: Season, ctx: Option<&Context>) -> Moment {
+    let jan1 = EpochDay(Gregorian::days_before_year(year as i64));
+    longitude_on_or_after(season.longitude(), jan1, ctx)
+}
+
+/// Moment (UT) of solar term `term` in Gregorian year `year`.
+///
+/// Terms are numbered from `0` (Lichun, 315°) to `23` (Dahan, 300°) in
+/// steps of 15°, as in [`SOLAR_TERM_NAMES`]; all 24 fall within the year.
+///
+/// # Errors
+/// Returns [`CalError::InvalidDate`] if `term > 23`.
+pub fn solar_term(year: i32, term: u8, ctx: Option<&Context>) -> Result<Moment, CalError> {
+    if term > 23 {
+        return Err(CalError::InvalidDate);
+    }
+    let lambda = normalize_deg(315.0 + 15.0 * term as f64);
+    let jan1 = EpochDay(Gregorian::days_before_year(year as i64));
+    Ok(longitude_on_or_after(lambda, jan1, ctx))
+}
+
+/// The 24 solar terms (jieqi), from Lichun (315°).
+pub const SOLAR_TERM_NAMES: [&str; 24] = [
+    "Lichun",
+    "Yushui",
+    "Jingzhe",
+    "Chunfen",
+    "Qingming",
+    "Guyu",
+    "Lixia",
+    "Xiaoman",
+    "Mangzhong",
+    "Xiazhi",
+    "Xiaoshu",
+    "Dashu",
+    "Liqiu",
+    "Chushu",
+    "Bailu",
+    "Qiufen",
+    "Hanlu",
+    "Shuangjiang",
+    "Lidong",
+    "Xiaoxue",
+    "Daxue",
+    "Dongzhi",
+    "Xiaohan",
+    "Dahan",
+];
+pub const SOLAR_TERM_NAMES_CHINESE: [&str; 24] = [
+    "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种", "夏至", "小暑", "大暑",
+    "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至", "小寒", "大寒",
+];
@@ -0,0 +1,148 @@
+//! Time scales and ΔT.
+
+use super::poly;
+use crate::core::context::Context;
+
+pub(crate) use crate::core::moment::JD_OFFSET;
+
+/// J2000.0 (2000-01-01 12:00 TT) as an RD moment.
+pub(crate) const J2000: f64 = 730_119.5;
+
+/// Julian centuries since J2000.0 for a moment in Terrestrial Time.
+#[inline]
+pub(crate) fn julian_centuries(tt: f64) -> f64 {
+    (tt - J2000) / 36525.0
+}
+
+/// ΔT = TT − UT in days near the UT moment `ut`: the context value if set,
+/// otherwise [`estimate_delta_t`].
+pub(crate) fn delta_t(ctx: Option<&Context>, ut: f64) -> f64 {
+    let seconds = match ctx.and_then(|c| c.delta_t_seconds) {
+        Some(s) => s,
+        None => estimate_delta_t(decimal_year(ut)),
+    };
+    seconds / 86_400.0
+}
+
+/// Gregorian year with fraction, close enough for ΔT.
+#[inline]
+fn decimal_year(ut: f64) -> f64 {
+    1.0 + ut / 365.2425
+}
+
+/// Estimated ΔT = TT − UT in seconds for a decimal year (e.g. `2024.5`).
+///
+/// With the `tables` feature, observed values are used from 1620 to 2024;
+/// later years follow the polynomials, shifted to join the table with the
+/// shift fading out by 2050. Elsewhere the Espenak–Meeus polynomials are used.
+///
+/// # Example
+/// ```
+/// use calz::astro::time;
+///
+/// // ΔT was 63.8 s at the start of 2000
+/// assert!((time::estimate_delta_t(2000.0) - 63.8).abs() < 0.1);
+/// ```
+pub fn estimate_delta_t(year: f64) -> f64 {
+    #[cfg(feature = "tables")]
+    {
+        use crate::tables::delta_t;
+
+        if let Some(observed) = delta_t::lookup(year) {
+            return observed;
+        }
+        let fade = 1.0 - (year - delta_t::LAST_YEAR) / (2050.0 - delta_t::LAST_YEAR);
+        if (0.0..1.0).contains(&fade) {
+            let last = delta_t::lookup(delta_t::LAST_YEAR).unwrap_or_default();
+            let shift = last - espenak_meeus(delta_t::LAST_YEAR);
+            return espenak_meeus(year) + fade * shift;
+        }
+    }
+    espenak_meeus(year)
+}
+
+/// Espenak & Meeus (2006) polynomial expressions for ΔT in seconds.
+fn espenak_meeus(y: f64) -> f64 {
+    // Long-term parabola from Morrison & Stephenson (2004)
+    let parabola = |y: f64| {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+    match y {
+        y if y < -500.0 => parabola(y),
+        y if y < 500.0 => poly(
+            y / 100.0,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        ),
+        y if y < 1600.0 => poly(
+            (y - 1000.0) / 100.0,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        ),
+        y if y < 1700.0 => poly(y - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0]),
+        y if y < 1800.0 => poly(
+            y - 1700.0,
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1_174_000.0],
+        ),
+        y if y < 1860.0 => poly(
+            y - 1800.0,
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+        ),
+        y if y < 1900.0 => poly(
+            y - 1860.0,
+            &[
+                7.62,
+                0.5737,
+                -0.251754,
+                0.01680668,
+                -0.0004473624,
+                1.0 / 233_174.0,
+            ],
+        ),
+        y if y < 1920.0 => poly(
+            y - 1900.0,
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+        ),
+        y if y < 1941.0 => poly(y - 1920.0, &[21.20, 0.84493, -0.076100, 0.0020936]),
+        y if y < 1961.0 => poly(y - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0]),
+        y if y < 1986.0 => poly(y - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0]),
+        y if y < 2005.0 => poly(
+            y - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        ),
+        y if y < 2050.0 => poly(y - 2000.0, &[62.92, 0.32217, 0.005589]),
+        y if y < 2150.0 => parabola(y) - 0.5628 * (2150.0 - y),
+        y => parabola(y),
+    }
+}
@@ -0,0 +1,235 @@
+//! Visibility of the new lunar crescent after sunset.
+
+use super::{Location, cos_deg, horizon, lunar, sin_deg, solar, time};
+use crate::core::error::CalError;
+
+/// Rule predicting whether the new crescent can be seen on a given evening.
+///
+/// # Example
+/// ```
+/// use calz::astro::visibility::Criterion;
+/// use calz::calendars::gregorian::{self, Gregorian};
+/// use calz::calendars::islamic::{self, Islamic, Variant};
+/// use calz::{Context, convert};
+///
+/// // Ramadan 1445 began on 2024-03-12 in Morocco, Oman and Pakistan, and on
+/// // 03-11 in Saudi Arabia
+/// let ramadan = islamic::Date { year: 1445, month: 9, day: 1 };
+/// let starts = [
+///     (Criterion::Odeh, 34.02, -6.83, 12),               // Rabat
+///     (Criterion::Yallop, 23.59, 58.38, 12),             // Muscat
+///     (Criterion::Shaukat, 24.86, 67.01, 12),            // Karachi
+///     (Criterion::MoonsetAfterSunset, 21.42, 39.83, 11), // Mecca
+/// ];
+/// for (criterion, lat, lon, day) in starts {
+///     let ctx = Context {
+///         latitude_deg: Some(lat),
+///         longitude_deg: Some(lon),
+///         ..Default::default()
+///     };
+///     let var = Variant::Observational { criterion };
+///     let start = convert::<Islamic, Gregorian>(&ramadan, &var, &Default::default(), Some(&ctx))
+///         .unwrap();
+///     assert_eq!(start, gregorian::Date { year: 2024, month: 3, day });
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Criterion {
+    /// Odeh (2004): visible to the naked eye when `V ≥ 5.65` at the best time,
+    /// from topocentric arc of vision and crescent width.
+    Odeh,
+    /// Yallop (1997, NAO TN 69): visible to the naked eye when `q > −0.014`
+    /// (categories A and B) at the best time.
+    Yallop,
+    /// Shaukat's variant of the q-test: geometry taken topocentrically at
+    /// sunset, visible when `q > −0.14`.
+    Shaukat,
+    /// The Moon sets after the Sun and conjunction happened before sunset.
+    MoonsetAfterSunset,
+}
+
+/// Sun–Moon geometry seen from the observer.
+struct Geometry {
+    /// Geocentric altitude of the Moon minus that of the Sun.
+    arcv_geocentric: f64,
+    /// Topocentric altitude of the Moon minus that of the Sun.
+    arcv: f64,
+    /// Topocentric crescent width in arcminutes.
+    width: f64,
+}
+
+impl Geometry {
+    fn at(ut: f64, dt: f64, loc: &Location) -> Self {
+        let c = time::julian_centuries(ut + dt);
+        let (sun_ra, sun_dec) = solar::equatorial(c);
+        let (moon_ra, moon_dec, distance) = lunar::equatorial(c);
+        let (sun_alt, sun_az) = horizon::horizontal(ut, sun_ra, sun_dec, loc);
+        let (moon_alt, moon_az) = horizon::horizontal(ut, moon_ra, moon_dec, loc);
+
+        let parallax = (6378.14 / distance).asin().to_degrees();
+        let moon_alt_topo = moon_alt - parallax * cos_deg(moon_alt);
+        let arcv = moon_alt_topo - sun_alt;
+        let arcl = (cos_deg(arcv) * cos_deg(sun_az - moon_az))
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees();
+
+        // Semi-diameter in arcminutes, enlarged by the Moon's altitude
+        let semi_diameter =
+            0.27245 * parallax * 60.0 * (1.0 + sin_deg(moon_alt_topo) * sin_deg(parallax));
+        Self {
+            arcv_geocentric: moon_alt - sun_alt,
+            arcv,
+            width: semi_diameter * (1.0 - cos_deg(arcl)),
+        }
+    }
+}
+
+/// Minimum arc of vision for a crescent of width `w` (Yallop), less `offset`.
+#[inline]
+fn min_arcv(w: f64, offset: f64) -> f64 {
+    11.8371 - offset - 6.3226 * w + 0.7319 * w * w - 0.1018 * w * w * w
+}
+
+/// Is the new crescent visible on the evening of local day `day` at `loc`?
+pub(crate) fn is_visible(criterion: Criterion, day: i64, dt: f64, loc: &Location) -> bool {
+    let Some(sunset) = horizon::sunset(day, dt, loc) else {
+        return false;
+    };
+    // Conjunction must have happened, and the Moon must still be young
+    let phase = lunar::phase(sunset, dt);
+    if !(0.0 < phase && phase < 90.0) {
+        return false;
+    }
+    let Some(lag) = horizon::moonlag(day, dt, loc).filter(|&lag| lag > 0.0) else {
+        return false;
+    };
+    // Yallop's best time for observation
+    let best = sunset + 4.0 / 9.0 * lag;
+
+    match criterion {
+        Criterion::MoonsetAfterSunset => true,
+        Criterion::Yallop => {
+            let g = Geometry::at(best, dt, loc);
+            (g.arcv_geocentric - min_arcv(g.width, 0.0)) / 10.0 > -0.014
+        }
+        Criterion::Odeh => {
+            let g = Geometry::at(best, dt, loc);
+            g.arcv - min_arcv(g.width, 4.672) >= 5.65
+        }
+        Criterion::Shaukat => {
+            let g = Geometry::at(sunset, dt, loc);
+            (g.arcv - min_arcv(g.width, 0.0)) / 10.0 > -0.14
+        }
+    }
+}
+
+// Conditions of the 2016 Istanbul unified Hijri calendar
+const UNIFIED_MIN_ELONGATION: f64 = 8.0;
+const UNIFIED_MIN_ALTITUDE: f64 = 5.0;
+
+/// Depression of the Sun at dawn (fajr) for the America exception.
+const FAJR_DEPRESSION: f64 = 18.0;
+
+/// Wellington, for the America exception.
+const NEW_ZEALAND: Location = Location {
+    latitude_deg: -41.2865,
+    longitude_deg: 174.7762,
+    zone_hours: 12.0,
+};
+
+/// Rough west and east coasts of the American mainland every 5° of
+/// latitude, for the America exception.
+#[rustfmt::skip]
+const AMERICAS: [(i32, i32, i32); 23] = [
+    (-50, -75, -68), (-45, -74, -65), (-40, -73, -62), (-35, -72, -57),
+    (-30, -71, -50), (-25, -70, -47), (-20, -70, -40), (-15, -76, -39),
+    (-10, -78, -36), (-5, -81, -35), (0, -80, -50), (5, -77, -52),
+    (10, -85, -61), (15, -92, -83), (20, -105, -87), (25, -112, -97),
+    (30, -115, -81), (35, -120, -76), (40, -124, -74), (45, -124, -67),
+    (50, -127, -56), (55, -131, -60), (60, -165, -65),
+];
+
+/// Topocentric altitude of the Moon and geocentric Sun–Moon elongation at a
+/// UT moment.
+fn altitude_and_elongation(ut: f64, dt: f64, loc: &Location) -> (f64, f64) {
+    let c = time::julian_centuries(ut + dt);
+    let (sun_ra, sun_dec) = solar::equatorial(c);
+    let (moon_ra, moon_dec, distance) = lunar::equatorial(c);
+    let (alt, _) = horizon::horizontal(ut, moon_ra, moon_dec, loc);
+    let parallax = (6378.14 / distance).asin().to_degrees();
+    let alt = alt - parallax * cos_deg(alt);
+    let elongation = (sin_deg(sun_dec) * sin_deg(moon_dec)
+        + cos_deg(sun_dec) * cos_deg(moon_dec) * cos_deg(sun_ra - moon_ra))
+    .clamp(-1.0, 1.0)
+    .acos()
+    .to_degrees();
+    (alt, elongation)
+}
+
+/// Sunset moment on the eve of UT day `day` at (`lat`, `lon`), if it meets
+/// the unified criterion there.
+fn unified_sighting(day: i64, lat: f64, lon: f64, dt: f64) -> Option<f64> {
+    let loc = Location::new(lat, lon);
+    let sunset = horizon::sunset(day - 1, dt, &loc)?;
+    if lunar::phase(sunset, dt) >= 90.0 {
+        return None;
+    }
+    let (alt, elongation) = altitude_and_elongation(sunset, dt, &loc);
+    (alt >= UNIFIED_MIN_ALTITUDE && elongation >= UNIFIED_MIN_ELONGATION).then_some(sunset)
+}
+
+/// Does the unified (Diyanet, Istanbul 2016) criterion start a month on UT
+/// day `day`?
+///
+/// The Moon must reach 5° of altitude and 8° of elongation at sunset
+/// somewhere on Earth before 00:00 UT. Failing that, a sighting later that
+/// evening on the American mainland also counts, provided conjunction came
+/// before fajr in New Zealand. Locations are sampled every 5° between
+/// latitudes ±60°; the mainland is only outlined roughly.
+pub(crate) fn unified_month_starts(day: i64, dt: f64) -> bool {
+    let midnight = day as f64;
+    // Elongation barely depends on the observer; skip hopeless evenings
+    let (_, elongation) = altitude_and_elongation(midnight + 0.5, dt, &NEW_ZEALAND);
+    if elongation < UNIFIED_MIN_ELONGATION {
+        return false;
+    }
+
+    let mut sightings = (-12..=12)
+        .flat_map(|lat| (-36..=36).map(move |lon| (5 * lat, 5 * lon)))
+        .filter_map(|(lat, lon)| unified_sighting(day, lat as f64, lon as f64, dt));
+    if sightings.any(|sunset| sunset < midnight) {
+        return true;
+    }
+
+    // America exception
+    let conjunction = lunar::new_moon_at_or_before(midnight + 0.5, dt);
+    let before_fajr = horizon::dawn(day, FAJR_DEPRESSION, dt, &NEW_ZEALAND)
+        .is_some_and(|fajr| conjunction < fajr);
+    before_fajr
+        && AMERICAS
+            .iter()
+            .flat_map(|&(lat, west, east)| (west..=east).step_by(5).map(move |lon| (lat, lon)))
+            .any(|(lat, lon)| unified_sighting(day, lat as f64, lon as f64, dt).is_some())
+}
+
+/// First day of the lunar month containing `date`, for months that begin the
+/// day after the crescent is first seen. `visible(d)` tells whether the
+/// crescent is visible on the eve of day `d`.
+pub(crate) fn month_start_on_or_before(
+    date: i64,
+    dt: f64,
+    visible: impl Fn(i64) -> bool,
+) -> Result<i64, CalError> {
+    let moon = lunar::new_moon_at_or_before(date as f64, dt).floor() as i64;
+    let age = date - moon;
+    // Too young to be seen yet: the month began after the previous conjunction
+    let tau = if age <= 3 && !visible(date) {
+        moon - 30
+    } else {
+        moon
+    };
+    (tau..tau + 45)
+        .find(|&d| visible(d))
+        .ok_or(CalError::ConversionFailed)
+}
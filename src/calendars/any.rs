@@ -0,0 +1,376 @@
+//! Calendars chosen at runtime, by value or by identifier.
+
+#[cfg(feature = "astro")]
+use super::babylonian::{self, Babylonian};
+use super::{
+    coptic::{self, Coptic},
+    fiscal::{self, Fiscal},
+    gregorian::{self, Gregorian},
+    icelandic::{self, Icelandic},
+    international_fixed::{self, InternationalFixed},
+    islamic::{self, Islamic},
+    julian::{self, Julian},
+    persian::{self, Persian},
+    roman::{self, Roman},
+    symmetry454::{self, Symmetry454},
+};
+use crate::core::{
+    context::Context,
+    epoch::EpochDay,
+    error::CalError,
+    moment::{DayBoundary, Moment},
+    traits::Calendar,
+};
+use std::fmt;
+use std::str::FromStr;
+
+/// A calendar and variant selected at runtime.
+///
+/// Also a [`Calendar`] whose variant is the selection itself, so `convert`
+/// and `CalDate` work with it. Non-exhaustive: the `astro` feature adds
+/// calendars.
+///
+/// # Example
+/// ```
+/// use calz::calendars::any::AnyCalendar;
+///
+/// let gregorian: AnyCalendar = "gregorian".parse().unwrap();
+/// let date = gregorian.date(2025, 3, 1).unwrap();
+/// let hijri = AnyCalendar::convert_ids("gregorian", &date, "islamic-civil", None).unwrap();
+/// assert_eq!(hijri.ymd(), Some((1446, 9, 1)));
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum AnyCalendar {
+    #[cfg(feature = "astro")]
+    Babylonian(babylonian::Variant),
+    Coptic(coptic::Variant),
+    Fiscal(fiscal::Variant),
+    Gregorian(gregorian::Variant),
+    Icelandic(icelandic::Variant),
+    InternationalFixed(international_fixed::Variant),
+    Islamic(islamic::Variant),
+    Julian(julian::Variant),
+    Persian(persian::Variant),
+    Roman(roman::Variant),
+    Symmetry454(symmetry454::Variant),
+}
+
+/// A date in any calendar, in its native form; non-exhaustive like
+/// [`AnyCalendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnyDate {
+    #[cfg(feature = "astro")]
+    Babylonian(babylonian::Date),
+    Coptic(coptic::Date),
+    Fiscal(fiscal::Date),
+    Gregorian(gregorian::Date),
+    Icelandic(icelandic::Date),
+    InternationalFixed(international_fixed::Date),
+    Islamic(islamic::Date),
+    Julian(julian::Date),
+    Persian(persian::Date),
+    Roman(roman::Date),
+    Symmetry454(symmetry454::Date),
+}
+
+impl AnyCalendar {
+    /// Identifiers and the calendars they select. Islamic identifiers follow
+    /// CLDR where one exists.
+    pub const REGISTRY: &'static [(&'static str, AnyCalendar)] = &[
+        #[cfg(feature = "astro")]
+        (
+            "babylonian",
+            AnyCalendar::Babylonian(babylonian::Variant::Observational),
+        ),
+        ("coptic", AnyCalendar::Coptic(coptic::Variant::Proleptic)),
+        (
+            "fiscal-retail",
+            AnyCalendar::Fiscal(fiscal::Variant::Retail),
+        ),
+        (
+            "gregorian",
+            AnyCalendar::Gregorian(gregorian::Variant::Proleptic),
+        ),
+        (
+            "gregorian-cutover",
+            AnyCalendar::Gregorian(gregorian::Variant::Cutover),
+        ),
+        (
+            "icelandic",
+            AnyCalendar::Icelandic(icelandic::Variant::Modern),
+        ),
+        (
+            "international-fixed",
+            AnyCalendar::InternationalFixed(international_fixed::Variant::Proleptic),
+        ),
+        (
+            "islamic-civil",
+            AnyCalendar::Islamic(islamic::Variant::Civil),
+        ),
+        (
+            "islamic-tbla",
+            AnyCalendar::Islamic(islamic::Variant::Tabular {
+                leap: islamic::LeapPattern::Type16,
+                epoch: islamic::Epoch::Thursday,
+            }),
+        ),
+        (
+            "islamic-umalqura",
+            AnyCalendar::Islamic(islamic::Variant::UmmAlQura),
+        ),
+        #[cfg(feature = "astro")]
+        (
+            "islamic-unified",
+            AnyCalendar::Islamic(islamic::Variant::Unified),
+        ),
+        ("julian", AnyCalendar::Julian(julian::Variant::Proleptic)),
+        (
+            "persian",
+            AnyCalendar::Persian(persian::Variant::Arithmetic),
+        ),
+        ("roman", AnyCalendar::Roman(roman::Variant::Julian)),
+        (
+            "symmetry454",
+            AnyCalendar::Symmetry454(symmetry454::Variant::Proleptic),
+        ),
+    ];
+
+    /// Calendar registered under `id`.
+    ///
+    /// # Errors
+    /// Returns [`CalError::UnknownCalendar`] if no calendar has that identifier.
+    pub fn from_id(id: &str) -> Result<Self, CalError> {
+        Self::REGISTRY
+            .iter()
+            .find(|(name, _)| *name == id)
+            .map(|(_, cal)| cal.clone())
+            .ok_or(CalError::UnknownCalendar)
+    }
+
+    /// Identifier of the calendar, if it is in [`AnyCalendar::REGISTRY`].
+    pub fn id(&self) -> Option<&'static str> {
+        use islamic::{Epoch, LeapPattern};
+
+        Some(match self {
+            #[cfg(feature = "astro")]
+            AnyCalendar::Babylonian(babylonian::Variant::Observational) => "babylonian",
+            AnyCalendar::Coptic(_) => "coptic",
+            AnyCalendar::Fiscal(_) => "fiscal-retail",
+            AnyCalendar::Gregorian(gregorian::Variant::Proleptic) => "gregorian",
+            AnyCalendar::Gregorian(gregorian::Variant::Cutover) => "gregorian-cutover",
+            AnyCalendar::Icelandic(_) => "icelandic",
+            AnyCalendar::InternationalFixed(_) => "international-fixed",
+            AnyCalendar::Islamic(islamic::Variant::Civil) => "islamic-civil",
+            AnyCalendar::Islamic(islamic::Variant::Tabular {
+                leap: LeapPattern::Type16,
+                epoch: Epoch::Thursday,
+            }) => "islamic-tbla",
+            AnyCalendar::Islamic(islamic::Variant::UmmAlQura) => "islamic-umalqura",
+            #[cfg(feature = "astro")]
+            AnyCalendar::Islamic(islamic::Variant::Unified) => "islamic-unified",
+            AnyCalendar::Julian(_) => "julian",
+            AnyCalendar::Persian(_) => "persian",
+            AnyCalendar::Roman(_) => "roman",
+            AnyCalendar::Symmetry454(_) => "symmetry454",
+            _ => return None,
+        })
+    }
+
+    /// When days of the selected calendar begin.
+    pub fn day_boundary(&self) -> DayBoundary {
+        match self {
+            #[cfg(feature = "astro")]
+            AnyCalendar::Babylonian(_) => Babylonian::DAY_BOUNDARY,
+            AnyCalendar::Islamic(_) => Islamic::DAY_BOUNDARY,
+            _ => DayBoundary::Midnight,
+        }
+    }
+
+    /// Year-month-day date, for calendars counted that way.
+    ///
+    /// The date is not checked; conversions report invalid ones.
+    ///
+    /// # Errors
+    /// Returns [`CalError::CalendarMismatch`] for calendars whose dates are
+    /// not plain year, month and day.
+    pub fn date(&self, year: i32, month: u8, day: u8) -> Result<AnyDate, CalError> {
+        Ok(match self {
+            AnyCalendar::Coptic(_) => AnyDate::Coptic(coptic::Date { year, month, day }),
+            AnyCalendar::Gregorian(_) => AnyDate::Gregorian(gregorian::Date { year, month, day }),
+            AnyCalendar::InternationalFixed(_) => {
+                AnyDate::InternationalFixed(international_fixed::Date { year, month, day })
+            }
+            AnyCalendar::Islamic(_) => AnyDate::Islamic(islamic::Date { year, month, day }),
+            AnyCalendar::Julian(_) => AnyDate::Julian(julian::Date { year, month, day }),
+            AnyCalendar::Persian(_) => AnyDate::Persian(persian::Date { year, month, day }),
+            AnyCalendar::Symmetry454(_) => {
+                AnyDate::Symmetry454(symmetry454::Date { year, month, day })
+            }
+            _ => return Err(CalError::CalendarMismatch),
+        })
+    }
+
+    /// Converts `date` from this calendar to `to`.
+    ///
+    /// # Errors
+    /// As for [`Calendar::to_epoch_day`] and [`Calendar::from_epoch_day`].
+    pub fn convert(
+        &self,
+        date: &AnyDate,
+        to: &AnyCalendar,
+        ctx: Option<&Context>,
+    ) -> Result<AnyDate, CalError> {
+        let ed = Self::to_epoch_day(date, self, ctx)?;
+        Self::from_epoch_day(ed, to, ctx)
+    }
+
+    /// Converts `date` between calendars named by identifiers.
+    ///
+    /// # Errors
+    /// Returns [`CalError::UnknownCalendar`] for an unknown identifier,
+    /// otherwise as for [`AnyCalendar::convert`].
+    pub fn convert_ids(
+        from: &str,
+        date: &AnyDate,
+        to: &str,
+        ctx: Option<&Context>,
+    ) -> Result<AnyDate, CalError> {
+        Self::from_id(from)?.convert(date, &Self::from_id(to)?, ctx)
+    }
+}
+
+impl FromStr for AnyCalendar {
+    type Err = CalError;
+
+    fn from_str(s: &str) -> Result<Self, CalError> {
+        Self::from_id(s)
+    }
+}
+
+impl AnyDate {
+    /// Year, month and day, for calendars counted that way.
+    pub fn ymd(&self) -> Option<(i32, u8, u8)> {
+        match *self {
+            AnyDate::Coptic(coptic::Date { year, month, day })
+            | AnyDate::Gregorian(gregorian::Date { year, month, day })
+            | AnyDate::InternationalFixed(international_fixed::Date { year, month, day })
+            | AnyDate::Islamic(islamic::Date { year, month, day })
+            | AnyDate::Julian(julian::Date { year, month, day })
+            | AnyDate::Persian(persian::Date { year, month, day })
+            | AnyDate::Symmetry454(symmetry454::Date { year, month, day }) => {
+                Some((year, month, day))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AnyDate {
+    /// `YYYY-MM-DD` where the calendar allows it, the native form otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self, self.ymd()) {
+            (AnyDate::Roman(date), _) => write!(f, "{date}"),
+            (_, Some((y, m, d))) => write!(f, "{y:04}-{m:02}-{d:02}"),
+            (date, None) => write!(f, "{date:?}"),
+        }
+    }
+}
+
+impl Calendar for AnyCalendar {
+    type Date = AnyDate;
+    type Variant = AnyCalendar;
+
+    /// # Errors
+    /// Returns [`CalError::CalendarMismatch`] if `date` is not a date of
+    /// calendar `var`.
+    fn to_epoch_day(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        match (var, date) {
+            #[cfg(feature = "astro")]
+            (AnyCalendar::Babylonian(v), AnyDate::Babylonian(d)) => {
+                Babylonian::to_epoch_day(d, v, ctx)
+            }
+            (AnyCalendar::Coptic(v), AnyDate::Coptic(d)) => Coptic::to_epoch_day(d, v, ctx),
+            (AnyCalendar::Fiscal(v), AnyDate::Fiscal(d)) => Fiscal::to_epoch_day(d, v, ctx),
+            (AnyCalendar::Gregorian(v), AnyDate::Gregorian(d)) => {
+                Gregorian::to_epoch_day(d, v, ctx)
+            }
+            (AnyCalendar::Icelandic(v), AnyDate::Icelandic(d)) => {
+                Icelandic::to_epoch_day(d, v, ctx)
+            }
+            (AnyCalendar::InternationalFixed(v), AnyDate::InternationalFixed(d)) => {
+                InternationalFixed::to_epoch_day(d, v, ctx)
+            }
+            (AnyCalendar::Islamic(v), AnyDate::Islamic(d)) => Islamic::to_epoch_day(d, v, ctx),
+            (AnyCalendar::Julian(v), AnyDate::Julian(d)) => Julian::to_epoch_day(d, v, ctx),
+            (AnyCalendar::Persian(v), AnyDate::Persian(d)) => Persian::to_epoch_day(d, v, ctx),
+            (AnyCalendar::Roman(v), AnyDate::Roman(d)) => Roman::to_epoch_day(d, v, ctx),
+            (AnyCalendar::Symmetry454(v), AnyDate::Symmetry454(d)) => {
+                Symmetry454::to_epoch_day(d, v, ctx)
+            }
+            _ => Err(CalError::CalendarMismatch),
+        }
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        Ok(match var {
+            #[cfg(feature = "astro")]
+            AnyCalendar::Babylonian(v) => {
+                AnyDate::Babylonian(Babylonian::from_epoch_day(ed, v, ctx)?)
+            }
+            AnyCalendar::Coptic(v) => AnyDate::Coptic(Coptic::from_epoch_day(ed, v, ctx)?),
+            AnyCalendar::Fiscal(v) => AnyDate::Fiscal(Fiscal::from_epoch_day(ed, v, ctx)?),
+            AnyCalendar::Gregorian(v) => AnyDate::Gregorian(Gregorian::from_epoch_day(ed, v, ctx)?),
+            AnyCalendar::Icelandic(v) => AnyDate::Icelandic(Icelandic::from_epoch_day(ed, v, ctx)?),
+            AnyCalendar::InternationalFixed(v) => {
+                AnyDate::InternationalFixed(InternationalFixed::from_epoch_day(ed, v, ctx)?)
+            }
+            AnyCalendar::Islamic(v) => AnyDate::Islamic(Islamic::from_epoch_day(ed, v, ctx)?),
+            AnyCalendar::Julian(v) => AnyDate::Julian(Julian::from_epoch_day(ed, v, ctx)?),
+            AnyCalendar::Persian(v) => AnyDate::Persian(Persian::from_epoch_day(ed, v, ctx)?),
+            AnyCalendar::Roman(v) => AnyDate::Roman(Roman::from_epoch_day(ed, v, ctx)?),
+            AnyCalendar::Symmetry454(v) => {
+                AnyDate::Symmetry454(Symmetry454::from_epoch_day(ed, v, ctx)?)
+            }
+        })
+    }
+
+    fn to_moment(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Moment, CalError> {
+        match (var, date) {
+            #[cfg(feature = "astro")]
+            (AnyCalendar::Babylonian(v), AnyDate::Babylonian(d)) => {
+                Babylonian::to_moment(d, v, ctx)
+            }
+            _ => var
+                .day_boundary()
+                .start_of(Self::to_epoch_day(date, var, ctx)?, ctx),
+        }
+    }
+
+    fn from_moment(
+        m: Moment,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        match var {
+            #[cfg(feature = "astro")]
+            AnyCalendar::Babylonian(v) => {
+                Babylonian::from_moment(m, v, ctx).map(AnyDate::Babylonian)
+            }
+            _ => Self::from_epoch_day(var.day_boundary().day_at(m, ctx)?, var, ctx),
+        }
+    }
+}
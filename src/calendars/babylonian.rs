@@ -0,0 +1,256 @@
+use crate::astro::{Location, horizon, lunar, time, visibility};
+use crate::core::{
+    context::Context,
+    epoch::EpochDay,
+    error::CalError,
+    moment::{DayBoundary, Moment},
+    traits::Calendar,
+};
+use crate::util::math::div_floor;
+use std::sync::Arc;
+
+/// A Babylonian date in the Seleucid era.
+///
+/// `leap` marks the intercalary month: Addaru II (`month == 12`), or Ululu II
+/// (`month == 6`) in year 18 of the 19-year cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub leap: bool,
+    pub day: u8,
+}
+
+/// An attested first day of a Babylonian month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonthStart {
+    pub year: i32,
+    pub month: u8,
+    pub leap: bool,
+    pub start: EpochDay,
+}
+
+#[derive(Debug, Clone, Default)]
+pub enum Variant {
+    /// Months start on the evening the crescent is predicted to be visible.
+    #[default]
+    Observational,
+    /// Attested month starts, e.g. loaded from a chronology at runtime, take
+    /// precedence over predicted ones.
+    Attested(Arc<[MonthStart]>),
+}
+
+/// Babylonian lunisolar calendar (Seleucid era).
+///
+/// Months begin at sunset when the new crescent becomes visible, using the
+/// moonlag criterion: the Moon sets at least 48 minutes after the Sun and is
+/// less than a quarter old. Years follow the standard 19-year intercalation
+/// pattern.
+///
+/// The observer defaults to [`Babylonian::BABYLON`]; `latitude_deg`,
+/// `longitude_deg` and `timezone_offset_hours` in [`Context`] override it.
+/// ΔT is estimated unless `delta_t_seconds` is set.
+pub struct Babylonian;
+
+impl Babylonian {
+    /// Babylon, on local mean time.
+    pub const BABYLON: Location = Location {
+        latitude_deg: 32.4794,
+        longitude_deg: 44.4328,
+        zone_hours: 44.4328 / 15.0,
+    };
+
+    // Nisannu 1, 1 SE = 311 BC April 3 (Julian)
+    const EPOCH: i64 = -113_503;
+
+    // Minimum delay between sunset and moonset for a visible crescent
+    const MIN_MOONLAG: f64 = 48.0 / 1440.0;
+
+    /// `ctx` with the observer's coordinates defaulting to Babylon.
+    fn observer_context(ctx: Option<&Context>) -> Context {
+        let mut ctx = ctx.cloned().unwrap_or_default();
+        ctx.latitude_deg.get_or_insert(Self::BABYLON.latitude_deg);
+        ctx.longitude_deg.get_or_insert(Self::BABYLON.longitude_deg);
+        ctx
+    }
+
+    #[inline]
+    fn is_leap(y: i64) -> bool {
+        // 7 leap years in a 19-year cycle
+        (7 * y + 13).rem_euclid(19) < 7
+    }
+
+    /// Year 18 of the cycle intercalates Ululu instead of Addaru.
+    #[inline]
+    fn is_ululu_leap(y: i64) -> bool {
+        y.rem_euclid(19) == 18
+    }
+
+    #[inline]
+    fn months_before_year(y: i64) -> i64 {
+        div_floor(235 * (y - 1) + 13, 19)
+    }
+
+    /// Months elapsed since the epoch before the start of `date`'s month.
+    fn month_index(date: &Date) -> Result<i64, CalError> {
+        let (y, m) = (date.year as i64, date.month as i64);
+        if !(1..=12).contains(&m) {
+            return Err(CalError::InvalidDate);
+        }
+        let ululu = Self::is_ululu_leap(y);
+        if date.leap && !(Self::is_leap(y) && m == if ululu { 6 } else { 12 }) {
+            return Err(CalError::InvalidDate);
+        }
+        let ordinal = m + if date.leap || (ululu && m > 6) { 1 } else { 0 };
+        Ok(Self::months_before_year(y) + ordinal - 1)
+    }
+
+    /// Inverse of [`Self::month_index`]: (year, month, leap).
+    fn month_from_index(n: i64) -> (i64, i64, bool) {
+        let y = div_floor(19 * n + 5, 235) + 1;
+        let ordinal = n - Self::months_before_year(y) + 1;
+        if Self::is_ululu_leap(y) && ordinal > 6 {
+            (y, ordinal - 1, ordinal == 7)
+        } else if ordinal == 13 {
+            (y, 12, true)
+        } else {
+            (y, ordinal, false)
+        }
+    }
+
+    /// Is the new crescent visible on the eve of `date`?
+    fn criterion(date: i64, dt: f64, loc: &Location) -> bool {
+        let Some(set) = horizon::sunset(date - 1, dt, loc) else {
+            return false;
+        };
+        let phase = lunar::phase(set, dt);
+        0.0 < phase
+            && phase < 90.0
+            && horizon::moonlag(date - 1, dt, loc).is_some_and(|lag| lag >= Self::MIN_MOONLAG)
+    }
+
+    /// First day of the month containing `date`, as predicted.
+    #[inline]
+    fn new_month_on_or_before(date: i64, dt: f64, loc: &Location) -> Result<i64, CalError> {
+        visibility::month_start_on_or_before(date, dt, |d| Self::criterion(d, dt, loc))
+    }
+
+    /// First day of the `n`-th month since the epoch.
+    fn month_start(n: i64, var: &Variant, dt: f64, loc: &Location) -> Result<i64, CalError> {
+        if let Variant::Attested(table) = var
+            && let Some(entry) = table
+                .iter()
+                .find(|e| Self::month_index(&Self::entry_date(e)).is_ok_and(|i| i == n))
+        {
+            return Ok(entry.start.0);
+        }
+
+        let midmonth = Self::EPOCH + (n as f64 * lunar::MEAN_SYNODIC_MONTH).round() as i64 + 15;
+        Self::new_month_on_or_before(midmonth, dt, loc)
+    }
+
+    #[inline]
+    fn entry_date(entry: &MonthStart) -> Date {
+        Date {
+            year: entry.year,
+            month: entry.month,
+            leap: entry.leap,
+            day: 1,
+        }
+    }
+
+    pub const MONTH_NAMES: [&'static str; 12] = [
+        "Nisannu",
+        "Ayyaru",
+        "Simanu",
+        "Du'uzu",
+        "Abu",
+        "Ululu",
+        "Tashritu",
+        "Arahsamnu",
+        "Kislimu",
+        "Tebetu",
+        "Shabatu",
+        "Addaru",
+    ];
+}
+
+impl Calendar for Babylonian {
+    type Date = Date;
+    type Variant = Variant;
+
+    const DAY_BOUNDARY: DayBoundary = DayBoundary::Sunset;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        let n = Self::month_index(date)?;
+        let approx = Self::EPOCH as f64 + n as f64 * lunar::MEAN_SYNODIC_MONTH;
+        let dt = time::delta_t(ctx, approx);
+        let loc =
+            Location::from_context(ctx, Self::BABYLON)?.on_day(EpochDay(approx as i64), ctx)?;
+
+        let start = Self::month_start(n, var, dt, &loc)?;
+        let length = Self::month_start(n + 1, var, dt, &loc)? - start;
+        if date.day < 1 || date.day as i64 > length {
+            return Err(CalError::InvalidDate);
+        }
+        Ok(EpochDay(start + date.day as i64 - 1))
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        let dt = time::delta_t(ctx, ed.0 as f64);
+        let loc = Location::from_context(ctx, Self::BABYLON)?.on_day(ed, ctx)?;
+
+        let crescent = Self::new_month_on_or_before(ed.0, dt, &loc)?;
+        let mut n = ((crescent - Self::EPOCH) as f64 / lunar::MEAN_SYNODIC_MONTH).round() as i64;
+        let mut start = Self::month_start(n, var, dt, &loc)?;
+        // Attested starts may move the boundary by a day or two either way
+        while start > ed.0 {
+            n -= 1;
+            start = Self::month_start(n, var, dt, &loc)?;
+        }
+        loop {
+            let next = Self::month_start(n + 1, var, dt, &loc)?;
+            if next > ed.0 {
+                break;
+            }
+            n += 1;
+            start = next;
+        }
+
+        let (y, m, leap) = Self::month_from_index(n);
+        Ok(Date {
+            year: y as i32,
+            month: m as u8,
+            leap,
+            day: (ed.0 - start + 1) as u8,
+        })
+    }
+
+    fn to_moment(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Moment, CalError> {
+        let ctx = Self::observer_context(ctx);
+        let day = Self::to_epoch_day(date, var, Some(&ctx))?;
+        Self::DAY_BOUNDARY.start_of(day, Some(&ctx))
+    }
+
+    fn from_moment(
+        m: Moment,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        let ctx = Self::observer_context(ctx);
+        let day = Self::DAY_BOUNDARY.day_at(m, Some(&ctx))?;
+        Self::from_epoch_day(day, var, Some(&ctx))
+    }
+}
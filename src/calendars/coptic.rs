@@ -1,4 +1,9 @@
-use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+use crate::core::{
+    context::Context,
+    epoch::EpochDay,
+    error::CalError,
+    traits::{Calendar, CalendarInfo, check_range},
+};
 use crate::util::math::div_floor;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,11 +13,63 @@ pub struct Date {
     pub day: u8,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl Date {
+    /// Checked constructor.
+    ///
+    /// # Errors
+    /// Returns [`CalError::MonthOutOfRange`] or [`CalError::DayOutOfRange`]
+    /// if the month or day does not exist in `year`.
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self, CalError> {
+        if !(1..=13).contains(&month) {
+            return Err(CalError::MonthOutOfRange);
+        }
+        if day < 1 || day as i64 > Coptic::month_length(year as i64, month as i64) {
+            return Err(CalError::DayOutOfRange);
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Lenient constructor: months past the 13th and days past the end of
+    /// the month roll over into the following ones (and below 1, back into
+    /// the preceding ones), so 1741-13-06 is 1742-01-01.
+    ///
+    /// # Errors
+    /// Returns [`CalError::Overflow`] or [`CalError::OutOfRange`] if the
+    /// result is out of range.
+    pub fn normalized(year: i32, month: i64, day: i64) -> Result<Self, CalError> {
+        let months = month.checked_sub(1).ok_or(CalError::Overflow)?;
+        let y =
+            i32::try_from(year as i64 + div_floor(months, 13)).map_err(|_| CalError::Overflow)?;
+        let start = Coptic::ymd_to_rd(y as i64, months.rem_euclid(13) + 1, 1)?;
+        let ed = day
+            .checked_sub(1)
+            .and_then(|d| start.checked_add(d))
+            .ok_or(CalError::Overflow)?;
+        Coptic::from_epoch_day(EpochDay(ed), &Variant::Proleptic, None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
 pub enum Variant {
+    #[default]
     Proleptic,
 }
 
+/// Coptic calendar: twelve months of 30 days and five or six epagomenal days.
+///
+/// # Example
+/// ```
+/// use calz::calendars::coptic::{self, Coptic};
+/// use calz::core::traits::Calendar;
+/// use calz::EpochDay;
+///
+/// let var = coptic::Variant::Proleptic;
+/// // 1739 is a leap year: its sixth epagomenal day comes before 1 Thout 1740
+/// let leap_day = coptic::Date { year: 1739, month: 13, day: 6 };
+/// let day = Coptic::to_epoch_day(&leap_day, &var, None).unwrap();
+/// let next = Coptic::from_epoch_day(EpochDay(day.0 + 1), &var, None).unwrap();
+/// assert_eq!(next, coptic::Date { year: 1740, month: 1, day: 1 });
+/// ```
 pub struct Coptic;
 
 impl Coptic {
@@ -20,7 +77,24 @@ impl Coptic {
 
     #[inline]
     fn is_leap(y: i64) -> bool {
-        y % 4 == 3 // Coptic leap rule: year mod 4 == 3
+        y.rem_euclid(4) == 3 // Coptic leap rule: year mod 4 == 3
+    }
+
+    /// Twelve months of 30 days, then the epagomenal days.
+    #[inline]
+    fn month_length(y: i64, m: i64) -> i64 {
+        match m {
+            13 if Self::is_leap(y) => 6,
+            13 => 5,
+            _ => 30,
+        }
+    }
+
+    /// Days from the epoch to 1 Thout of year `y`; the leap day ends year
+    /// `4k + 3`.
+    #[inline]
+    const fn days_before_year(y: i64) -> i64 {
+        365 * (y - 1) + div_floor(y, 4)
     }
 
     fn ymd_to_rd(y: i64, m: i64, d: i64) -> Result<i64, CalError> {
@@ -31,25 +105,21 @@ impl Coptic {
             return Err(CalError::InvalidDate);
         }
 
-        // Days in month
-        let mdays = if m == 13 {
-            if Self::is_leap(y) { 6 } else { 5 }
-        } else {
-            30
-        };
-        if d > mdays {
+        if d > Self::month_length(y, m) {
             return Err(CalError::InvalidDate);
         }
 
-        let days_before_year = 365 * (y - 1) + div_floor(y - 1, 4);
         let doy = 30 * (m - 1) + (d - 1);
-        Ok(Self::EPOCH + days_before_year + doy)
+        let rd = Self::days_before_year(y)
+            .checked_add(Self::EPOCH + doy)
+            .ok_or(CalError::Overflow)?;
+        Ok(check_range::<Self>(EpochDay(rd))?.0)
     }
 
     fn rd_to_ymd(rd: i64) -> (i64, i64, i64) {
         let days = rd - Self::EPOCH;
-        let year = div_floor(days, 1461) * 4 + div_floor(days % 1461, 365) + 1;
-        let day_of_year = days - (365 * (year - 1) + div_floor(year - 1, 4));
+        let year = div_floor(4 * days + 1463, 1461);
+        let day_of_year = days - Self::days_before_year(year);
 
         let (month, day) = if day_of_year < 30 * 12 {
             (day_of_year / 30 + 1, day_of_year % 30 + 1)
@@ -126,6 +196,11 @@ impl Calendar for Coptic {
     type Date = Date;
     type Variant = Variant;
 
+    /// 1 Thout of year `i32::MIN`.
+    const MIN: EpochDay = EpochDay(Self::EPOCH + Self::days_before_year(i32::MIN as i64));
+    /// Last epagomenal day of year `i32::MAX`.
+    const MAX: EpochDay = EpochDay(Self::EPOCH + Self::days_before_year(i32::MAX as i64 + 1) - 1);
+
     fn to_epoch_day(
         date: &Self::Date,
         _var: &Self::Variant,
@@ -140,11 +215,66 @@ impl Calendar for Coptic {
         _var: &Self::Variant,
         _ctx: Option<&Context>,
     ) -> Result<Self::Date, CalError> {
-        let (y, m, d) = Self::rd_to_ymd(ed.0);
+        let (y, m, d) = Self::rd_to_ymd(check_range::<Self>(ed)?.0);
         Ok(Date {
-            year: y as i32,
+            year: i32::try_from(y).map_err(|_| CalError::OutOfRange)?,
             month: m as u8,
             day: d as u8,
         })
     }
 }
+
+impl CalendarInfo for Coptic {
+    fn is_leap_year(
+        year: i32,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<bool, CalError> {
+        Ok(Self::is_leap(year as i64))
+    }
+
+    fn months_in_year(
+        _year: i32,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<u8, CalError> {
+        Ok(13)
+    }
+
+    fn days_in_month(
+        year: i32,
+        month: u8,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<u8, CalError> {
+        if !(1..=13).contains(&month) {
+            return Err(CalError::InvalidDate);
+        }
+        Ok(Self::month_length(year as i64, month as i64) as u8)
+    }
+
+    fn first_day_of_year(
+        year: i32,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        Ok(EpochDay(Self::EPOCH + Self::days_before_year(year as i64)))
+    }
+
+    fn days_in_year(
+        year: i32,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<u16, CalError> {
+        Ok(if Self::is_leap(year as i64) { 366 } else { 365 })
+    }
+
+    fn day_of_year(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u16, CalError> {
+        let day = Self::to_epoch_day(date, var, ctx)?;
+        Ok((day.0 - Self::EPOCH - Self::days_before_year(date.year as i64) + 1) as u16)
+    }
+}
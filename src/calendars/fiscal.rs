@@ -0,0 +1,190 @@
+use crate::calendars::gregorian::Gregorian;
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+use crate::util::math::mod_floor;
+
+/// A date in a 52/53-week retail fiscal calendar.
+///
+/// `year` is named after the Gregorian year in which the fiscal year starts
+/// (NRF convention: fiscal 2024 ends in early 2025).
+/// `week` counts weeks of the fiscal year (`1..=53`) and `day` counts days of
+/// the week starting on Sunday (`1..=7`). `quarter` and `period` must agree
+/// with `week`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub quarter: u8,
+    pub period: u8,
+    pub week: u8,
+    pub day: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Variant {
+    /// Year-end rule and week pattern are read from [`Context::options`].
+    #[default]
+    Retail,
+}
+
+/// Rule deciding which Saturday ends the fiscal year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearEnd {
+    /// Last Saturday of January.
+    LastSaturdayOfJanuary,
+    /// Saturday nearest January 31 (may fall in early February).
+    SaturdayNearestJanuary31,
+}
+
+/// Number of weeks in each period of a quarter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pattern {
+    P445,
+    P454,
+    P544,
+}
+
+impl Pattern {
+    #[inline]
+    fn weeks(self) -> [i64; 3] {
+        match self {
+            Pattern::P445 => [4, 4, 5],
+            Pattern::P454 => [4, 5, 4],
+            Pattern::P544 => [5, 4, 4],
+        }
+    }
+}
+
+/// Retail (NRF-style) fiscal calendar with 4-4-5, 4-5-4 or 5-4-4 periods.
+///
+/// Fiscal years end on a Saturday and contain 52 or 53 whole weeks. In a
+/// 53-week year the extra week is appended to the last period.
+///
+/// Required [`Context::options`]:
+/// - [`Fiscal::YEAR_END_KEY`]: `"last_saturday_of_january"` or `"saturday_nearest_january_31"`
+/// - [`Fiscal::PATTERN_KEY`]: `"4-4-5"`, `"4-5-4"` or `"5-4-4"`
+pub struct Fiscal;
+
+impl Fiscal {
+    pub const YEAR_END_KEY: &'static str = "fiscal_year_end";
+    pub const PATTERN_KEY: &'static str = "fiscal_pattern";
+
+    // EpochDay(0) is a Monday
+    const SATURDAY: i64 = 5;
+
+    fn rules(ctx: Option<&Context>) -> Result<(YearEnd, Pattern), CalError> {
+        let ctx = ctx.ok_or(CalError::NeedsContext)?;
+        let year_end = match ctx.options.get(Self::YEAR_END_KEY).map(String::as_str) {
+            None => return Err(CalError::NeedsContext),
+            Some("last_saturday_of_january") => YearEnd::LastSaturdayOfJanuary,
+            Some("saturday_nearest_january_31") => YearEnd::SaturdayNearestJanuary31,
+            Some(_) => return Err(CalError::InvalidContext),
+        };
+        let pattern = match ctx.options.get(Self::PATTERN_KEY).map(String::as_str) {
+            None => return Err(CalError::NeedsContext),
+            Some("4-4-5") => Pattern::P445,
+            Some("4-5-4") => Pattern::P454,
+            Some("5-4-4") => Pattern::P544,
+            Some(_) => return Err(CalError::InvalidContext),
+        };
+        Ok((year_end, pattern))
+    }
+
+    /// Saturday on or before `rd`.
+    #[inline]
+    fn saturday_on_or_before(rd: i64) -> i64 {
+        rd - mod_floor(rd - Self::SATURDAY, 7)
+    }
+
+    /// Last day of fiscal year `y`.
+    fn year_end(y: i64, rule: YearEnd) -> i64 {
+        // January 31 of the following Gregorian year
+        let jan31 = Gregorian::days_before_year(y + 1) + 30;
+        match rule {
+            YearEnd::LastSaturdayOfJanuary => Self::saturday_on_or_before(jan31),
+            YearEnd::SaturdayNearestJanuary31 => Self::saturday_on_or_before(jan31 + 3),
+        }
+    }
+
+    #[inline]
+    fn year_start(y: i64, rule: YearEnd) -> i64 {
+        Self::year_end(y - 1, rule) + 1
+    }
+
+    /// Number of weeks in fiscal year `y` (52 or 53).
+    #[inline]
+    fn weeks_in_year(y: i64, rule: YearEnd) -> i64 {
+        (Self::year_end(y, rule) - Self::year_start(y, rule) + 1) / 7
+    }
+
+    /// Maps a 1-based week of the year to its (quarter, period).
+    fn week_to_period(week: i64, pattern: Pattern) -> (i64, i64) {
+        let weeks = pattern.weeks();
+        let w0 = (week - 1).min(51); // week 53 belongs to the last period
+        let quarter = w0 / 13;
+        let mut rem = w0 % 13;
+        let mut p = 0;
+        while rem >= weeks[p] {
+            rem -= weeks[p];
+            p += 1;
+        }
+        (quarter + 1, 3 * quarter + p as i64 + 1)
+    }
+
+    fn to_rd(date: &Date, rule: YearEnd, pattern: Pattern) -> Result<i64, CalError> {
+        let y = date.year as i64;
+        let week = date.week as i64;
+        let day = date.day as i64;
+        if week < 1 || week > Self::weeks_in_year(y, rule) {
+            return Err(CalError::InvalidDate);
+        }
+        if !(1..=7).contains(&day) {
+            return Err(CalError::InvalidDate);
+        }
+        let (quarter, period) = Self::week_to_period(week, pattern);
+        if quarter != date.quarter as i64 || period != date.period as i64 {
+            return Err(CalError::InvalidDate);
+        }
+
+        Ok(Self::year_start(y, rule) + 7 * (week - 1) + day - 1)
+    }
+
+    fn from_rd(rd: i64, rule: YearEnd, pattern: Pattern) -> Date {
+        // The fiscal year starts within a few days of February 1
+        let (mut year, _, _) = Gregorian::rd_to_ymd(rd);
+        if rd < Self::year_start(year, rule) {
+            year -= 1;
+        }
+        let days = rd - Self::year_start(year, rule);
+        let week = days / 7 + 1;
+        let (quarter, period) = Self::week_to_period(week, pattern);
+        Date {
+            year: year as i32,
+            quarter: quarter as u8,
+            period: period as u8,
+            week: week as u8,
+            day: (days % 7 + 1) as u8,
+        }
+    }
+}
+
+impl Calendar for Fiscal {
+    type Date = Date;
+    type Variant = Variant;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        _var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        let (rule, pattern) = Self::rules(ctx)?;
+        Ok(EpochDay(Self::to_rd(date, rule, pattern)?))
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        _var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        let (rule, pattern) = Self::rules(ctx)?;
+        Ok(Self::from_rd(ed.0, rule, pattern))
+    }
+}
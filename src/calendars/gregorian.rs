@@ -1,4 +1,10 @@
-use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+use crate::calendars::julian::Julian;
+use crate::core::{
+    context::Context,
+    epoch::EpochDay,
+    error::CalError,
+    traits::{Calendar, CalendarInfo, check_range},
+};
 use crate::util::math::div_floor;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,28 +14,123 @@ pub struct Date {
     pub day: u8,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl Date {
+    /// Checked constructor.
+    ///
+    /// # Errors
+    /// Returns [`CalError::MonthOutOfRange`] or [`CalError::DayOutOfRange`]
+    /// if the month or day does not exist in `year`.
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self, CalError> {
+        if !(1..=12).contains(&month) {
+            return Err(CalError::MonthOutOfRange);
+        }
+        if day < 1
+            || day as i64 > Gregorian::month_length(month as i64, Gregorian::is_leap(year as i64))
+        {
+            return Err(CalError::DayOutOfRange);
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Lenient constructor: months past December and days past the end of
+    /// the month roll over into the following ones (and below 1, back into
+    /// the preceding ones), so 2025-02-30 is 2025-03-02.
+    ///
+    /// # Errors
+    /// Returns [`CalError::Overflow`] or [`CalError::OutOfRange`] if the
+    /// result is out of range.
+    pub fn normalized(year: i32, month: i64, day: i64) -> Result<Self, CalError> {
+        let months = month.checked_sub(1).ok_or(CalError::Overflow)?;
+        let y =
+            i32::try_from(year as i64 + div_floor(months, 12)).map_err(|_| CalError::Overflow)?;
+        let start = Gregorian::ymd_to_rd(y as i64, months.rem_euclid(12) + 1, 1)?;
+        let ed = day
+            .checked_sub(1)
+            .and_then(|d| start.checked_add(d))
+            .ok_or(CalError::Overflow)?;
+        Gregorian::from_epoch_day(EpochDay(ed), &Variant::Proleptic, None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
 pub enum Variant {
+    /// Gregorian rules for every date.
+    #[default]
     Proleptic,
+    /// Julian dates before the reform: [`Gregorian::CUTOVER`], or the
+    /// [`Context::cutoffs`] entry [`Gregorian::CUTOVER_KEY`] if set. Days
+    /// dropped by the reform (1582-10-05 to 1582-10-14) are invalid.
     Cutover,
 }
 
 pub struct Gregorian;
 
 impl Gregorian {
+    /// First Gregorian day, 1582-10-15, following Julian 1582-10-04.
+    pub const CUTOVER: EpochDay = EpochDay(577_735);
+    /// [`Context::cutoffs`] key overriding [`Gregorian::CUTOVER`].
+    pub const CUTOVER_KEY: &'static str = "GregorianStart";
+
+    /// First Gregorian day under [`Variant::Cutover`].
+    fn cutover(ctx: Option<&Context>) -> i64 {
+        ctx.and_then(|c| c.cutoffs.get(Self::CUTOVER_KEY))
+            .map_or(Self::CUTOVER.0, |day| day.0)
+    }
+
+    /// Whether `year` follows the Julian leap rule under `var`, and whether
+    /// it is the year of the reform, which has days of both calendars.
+    fn julian_year(year: i64, var: &Variant, ctx: Option<&Context>) -> (bool, bool) {
+        let Variant::Cutover = var else {
+            return (false, false);
+        };
+        let cutover = Self::cutover(ctx);
+        let reform_year = Self::rd_to_ymd(cutover).0;
+        let julian = year < reform_year
+            || (year == reform_year
+                && Julian::ymd_to_rd(year, 2, 28).is_ok_and(|feb| feb < cutover));
+        (julian, year == reform_year)
+    }
+
+    /// Leap rule of `year` under `var`.
+    fn is_leap_in(year: i64, var: &Variant, ctx: Option<&Context>) -> bool {
+        match Self::julian_year(year, var, ctx) {
+            (true, _) => Julian::is_leap(year),
+            (false, _) => Self::is_leap(year),
+        }
+    }
+
     #[inline]
-    fn is_leap(y: i64) -> bool {
+    pub(crate) fn is_leap(y: i64) -> bool {
         (y % 4 == 0) && (y % 100 != 0 || y % 400 == 0)
     }
 
     /// Days before month in a non-leap year.
     #[inline]
-    fn doy_prefix(month: i64, leap: bool) -> i64 {
+    pub(crate) fn doy_prefix(month: i64, leap: bool) -> i64 {
         // 1-based month; 0 for Jan base
         const CUM: [i64; 13] = [0, 0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
         CUM[month as usize] + if leap && month > 2 { 1 } else { 0 }
     }
 
+    /// Days in month `month` (1-based).
+    #[inline]
+    fn month_length(month: i64, leap: bool) -> i64 {
+        match month {
+            2 if leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Count days in previous full years (Gregorian arithmetic).
+    /// This is the RD of January 1 of year `y`.
+    #[inline]
+    pub(crate) const fn days_before_year(y: i64) -> i64 {
+        let y1 = y - 1;
+        365 * y1 + div_floor(y1, 4) - div_floor(y1, 100) + div_floor(y1, 400)
+    }
+
     /// Convert Y-M-D to days since 0001-01-01 (RD epoch) using integer math.
     fn ymd_to_rd(y: i64, m: i64, d: i64) -> Result<i64, CalError> {
         if !(1..=12).contains(&m) {
@@ -40,36 +141,22 @@ impl Gregorian {
         }
 
         let leap = Self::is_leap(y);
-        let mdays = match m {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11 => 30,
-            2 => {
-                if leap {
-                    29
-                } else {
-                    28
-                }
-            }
-            _ => unreachable!(),
-        };
-        if d > mdays {
+        if d > Self::month_length(m, leap) {
             return Err(CalError::InvalidDate);
         }
 
-        // Count days in previous full years (Gregorian arithmetic)
-        let y1 = y - 1;
-        let days_before_year =
-            365 * y1 + div_floor(y1, 4) - div_floor(y1, 100) + div_floor(y1, 400);
-
         // Day-of-year offset
         let doy = Self::doy_prefix(m, leap) + d - 1;
 
         // RD day 0 == 0001-01-01
-        Ok(days_before_year + doy)
+        let rd = Self::days_before_year(y)
+            .checked_add(doy)
+            .ok_or(CalError::Overflow)?;
+        Ok(check_range::<Self>(EpochDay(rd))?.0)
     }
 
     /// Inverse: RD → Y-M-D (Hinnant-style integer inverse; no loops).
-    fn rd_to_ymd(rd: i64) -> (i64, i64, i64) {
+    pub(crate) fn rd_to_ymd(rd: i64) -> (i64, i64, i64) {
         // Convert RD where 0001-01-01 = 0 to civil y-m-d.
         // Decompose by 400/100/4/1-year cycles.
         let mut z = rd;
@@ -117,25 +204,144 @@ impl Calendar for Gregorian {
     type Date = Date;
     type Variant = Variant;
 
+    /// 1 January of year `i32::MIN`.
+    const MIN: EpochDay = EpochDay(Self::days_before_year(i32::MIN as i64));
+    /// 31 December of year `i32::MAX`.
+    const MAX: EpochDay = EpochDay(Self::days_before_year(i32::MAX as i64 + 1) - 1);
+
     fn to_epoch_day(
         date: &Self::Date,
-        _var: &Self::Variant,
-        _ctx: Option<&Context>,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
     ) -> Result<EpochDay, CalError> {
-        let rd = Self::ymd_to_rd(date.year as i64, date.month as i64, date.day as i64)?;
+        let (y, m, d) = (date.year as i64, date.month as i64, date.day as i64);
+        let rd = match var {
+            Variant::Proleptic => Self::ymd_to_rd(y, m, d)?,
+            Variant::Cutover => {
+                let cutover = Self::cutover(ctx);
+                match Self::ymd_to_rd(y, m, d) {
+                    Ok(rd) if rd >= cutover => rd,
+                    // Before the reform the date is Julian, unless the reform skipped it
+                    _ => match Julian::ymd_to_rd(y, m, d)? {
+                        rd if rd < cutover => check_range::<Self>(EpochDay(rd))?.0,
+                        _ => return Err(CalError::InvalidDate),
+                    },
+                }
+            }
+        };
         Ok(EpochDay(rd))
     }
 
     fn from_epoch_day(
         ed: EpochDay,
-        _var: &Self::Variant,
-        _ctx: Option<&Context>,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
     ) -> Result<Self::Date, CalError> {
-        let (y, m, d) = Self::rd_to_ymd(ed.0);
+        let ed = check_range::<Self>(ed)?;
+        let (y, m, d) = match var {
+            Variant::Cutover if ed.0 < Self::cutover(ctx) => Julian::rd_to_ymd(ed.0),
+            _ => Self::rd_to_ymd(ed.0),
+        };
         Ok(Date {
-            year: y as i32,
+            year: i32::try_from(y).map_err(|_| CalError::OutOfRange)?,
             month: m as u8,
             day: d as u8,
         })
     }
 }
+
+impl CalendarInfo for Gregorian {
+    fn is_leap_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<bool, CalError> {
+        Ok(Self::is_leap_in(year as i64, var, ctx))
+    }
+
+    fn months_in_year(
+        _year: i32,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<u8, CalError> {
+        Ok(12)
+    }
+
+    fn days_in_month(
+        year: i32,
+        month: u8,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u8, CalError> {
+        if !(1..=12).contains(&month) {
+            return Err(CalError::InvalidDate);
+        }
+        if let (_, true) = Self::julian_year(year as i64, var, ctx) {
+            // The reform month is shorter: count from its first day to the next
+            let start = Self::to_epoch_day(
+                &Date {
+                    year,
+                    month,
+                    day: 1,
+                },
+                var,
+                ctx,
+            )?;
+            let next = match month {
+                12 => Date::new(year.checked_add(1).ok_or(CalError::Overflow)?, 1, 1)?,
+                _ => Date::new(year, month + 1, 1)?,
+            };
+            let days = Self::to_epoch_day(&next, var, ctx)?.0 - start.0;
+            return u8::try_from(days).map_err(|_| CalError::OutOfRange);
+        }
+        let leap = Self::is_leap_in(year as i64, var, ctx);
+        Ok(Self::month_length(month as i64, leap) as u8)
+    }
+
+    fn first_day_of_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        match var {
+            Variant::Proleptic => Ok(EpochDay(Self::days_before_year(year as i64))),
+            Variant::Cutover => Self::to_epoch_day(
+                &Date {
+                    year,
+                    month: 1,
+                    day: 1,
+                },
+                var,
+                ctx,
+            ),
+        }
+    }
+
+    fn days_in_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u16, CalError> {
+        if let (_, true) = Self::julian_year(year as i64, var, ctx) {
+            return (1..=12).try_fold(0u16, |days, m| {
+                let month = Self::days_in_month(year, m, var, ctx)?;
+                days.checked_add(month as u16).ok_or(CalError::OutOfRange)
+            });
+        }
+        Ok(if Self::is_leap_in(year as i64, var, ctx) {
+            366
+        } else {
+            365
+        })
+    }
+
+    fn day_of_year(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u16, CalError> {
+        let day = Self::to_epoch_day(date, var, ctx)?;
+        let first = Self::first_day_of_year(date.year, var, ctx)?;
+        u16::try_from(day.0 - first.0 + 1).map_err(|_| CalError::OutOfRange)
+    }
+}
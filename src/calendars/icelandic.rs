@@ -0,0 +1,174 @@
+use crate::calendars::gregorian::Gregorian;
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+use crate::util::math::mod_floor;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Summer,
+    Winter,
+}
+
+/// A date in the old Icelandic calendar.
+///
+/// - `year`: Gregorian year in which the summer of this year begins
+/// - `month`: `1..=6` within the season; `0` for the extra summer nights
+///   (aukanætur and sumarauki) between the third and fourth summer months
+/// - `week`: week of the season, starting at 1
+/// - `weekday`: `0` = Sunday … `6` = Saturday
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub season: Season,
+    pub month: u8,
+    pub week: u8,
+    pub weekday: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Variant {
+    /// Summer starts on the Thursday from April 19 to 25 (Gregorian).
+    #[default]
+    Modern,
+}
+
+/// Old Icelandic calendar of 52 weeks (364 days) split into summer and winter.
+///
+/// Summer starts on a Thursday and winter on a Saturday, 180 days before the
+/// next summer. Whenever the year has to catch up with the Gregorian
+/// calendar, a whole week (sumarauki) is added to the summer.
+pub struct Icelandic;
+
+impl Icelandic {
+    const THURSDAY: i64 = 4;
+
+    /// Day of week, `0` = Sunday (EpochDay(0) is a Monday).
+    #[inline]
+    fn weekday(rd: i64) -> i64 {
+        mod_floor(rd + 1, 7)
+    }
+
+    /// First day of summer in Gregorian year `y`.
+    fn summer(y: i64) -> i64 {
+        let leap = if Gregorian::is_leap(y) { 1 } else { 0 };
+        let apr19 = Gregorian::days_before_year(y) + 108 + leap;
+        apr19 + mod_floor(Self::THURSDAY - Self::weekday(apr19), 7)
+    }
+
+    /// First day of winter that ends Icelandic year `y`.
+    #[inline]
+    fn winter(y: i64) -> i64 {
+        Self::summer(y + 1) - 180
+    }
+
+    /// Returns `true` if the summer of `year` carries the extra week (sumarauki).
+    pub fn has_sumarauki(year: i32) -> bool {
+        let y = year as i64;
+        Self::summer(y + 1) - Self::summer(y) == 371
+    }
+
+    /// Month within the season; `0` for the extra summer nights.
+    fn month(rd: i64, y: i64, season: Season) -> i64 {
+        let winter = Self::winter(y);
+        let start = match season {
+            Season::Winter => winter,
+            Season::Summer => {
+                // Three months of 30 days are counted from each end of the summer
+                let midsummer = winter - 90;
+                if rd >= midsummer {
+                    midsummer - 90
+                } else if rd < Self::summer(y) + 90 {
+                    Self::summer(y)
+                } else {
+                    return 0;
+                }
+            }
+        };
+        (rd - start) / 30 + 1
+    }
+
+    fn to_rd(date: &Date) -> Result<i64, CalError> {
+        let y = date.year as i64;
+        if date.week < 1 || date.weekday > 6 {
+            return Err(CalError::InvalidDate);
+        }
+        let start = match date.season {
+            Season::Summer => Self::summer(y),
+            Season::Winter => Self::winter(y),
+        };
+        // Weeks begin on the weekday the season starts on
+        let offset = mod_floor(date.weekday as i64 - Self::weekday(start), 7);
+        let rd = start + 7 * (date.week as i64 - 1) + offset;
+
+        // Rejects weeks past the end of the season and mismatched months
+        if Self::from_rd(rd) != *date {
+            return Err(CalError::InvalidDate);
+        }
+        Ok(rd)
+    }
+
+    fn from_rd(rd: i64) -> Date {
+        // The summer of year y starts in April of Gregorian year y
+        let (g_year, _, _) = Gregorian::rd_to_ymd(rd);
+        let year = if rd >= Self::summer(g_year) {
+            g_year
+        } else {
+            g_year - 1
+        };
+        let season = if rd < Self::winter(year) {
+            Season::Summer
+        } else {
+            Season::Winter
+        };
+        let start = match season {
+            Season::Summer => Self::summer(year),
+            Season::Winter => Self::winter(year),
+        };
+        Date {
+            year: year as i32,
+            season,
+            month: Self::month(rd, year, season) as u8,
+            week: ((rd - start) / 7 + 1) as u8,
+            weekday: Self::weekday(rd) as u8,
+        }
+    }
+
+    pub const SUMMER_MONTH_NAMES: [&'static str; 6] = [
+        "Harpa",
+        "Skerpla",
+        "Sólmánuður",
+        "Heyannir",
+        "Tvímánuður",
+        "Haustmánuður",
+    ];
+    pub const WINTER_MONTH_NAMES: [&'static str; 6] = [
+        "Gormánuður",
+        "Ýlir",
+        "Mörsugur",
+        "Þorri",
+        "Góa",
+        "Einmánuður",
+    ];
+    /// Name of the extra summer nights (month `0`).
+    pub const EXTRA_NIGHTS_NAME: &'static str = "Aukanætur";
+}
+
+impl Calendar for Icelandic {
+    type Date = Date;
+    type Variant = Variant;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        Ok(EpochDay(Self::to_rd(date)?))
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        Ok(Self::from_rd(ed.0))
+    }
+}
@@ -0,0 +1,130 @@
+use crate::calendars::gregorian::Gregorian;
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+
+/// A date in the International Fixed Calendar.
+///
+/// Regular days use months `1..=13` and days `1..=28`. The two intercalary
+/// days that stand outside the week are encoded as day 29:
+/// - Leap Day: month 6, day 29 (Gregorian leap years only)
+/// - Year Day: month 13, day 29
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Returns `true` if this is the Leap Day following June 28.
+    #[inline]
+    pub fn is_leap_day(&self) -> bool {
+        self.month == 6 && self.day == 29
+    }
+
+    /// Returns `true` if this is the Year Day following December 28.
+    #[inline]
+    pub fn is_year_day(&self) -> bool {
+        self.month == 13 && self.day == 29
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Variant {
+    #[default]
+    Proleptic,
+}
+
+/// International Fixed Calendar (Cotsworth plan).
+///
+/// Thirteen months of exactly four weeks, with "Sol" inserted between June and
+/// July. The year starts on Gregorian January 1 and uses the Gregorian leap rule.
+pub struct InternationalFixed;
+
+impl InternationalFixed {
+    // Day-of-year (0-based) of Leap Day, right after June 28
+    const LEAP_DAY: i64 = 6 * 28;
+
+    fn ymd_to_rd(y: i64, m: i64, d: i64) -> Result<i64, CalError> {
+        if !(1..=13).contains(&m) {
+            return Err(CalError::InvalidDate);
+        }
+
+        let leap = Gregorian::is_leap(y);
+        let max_day = match m {
+            13 => 29,
+            6 if leap => 29,
+            _ => 28,
+        };
+        if d < 1 || d > max_day {
+            return Err(CalError::InvalidDate);
+        }
+
+        let mut doy = 28 * (m - 1) + d - 1;
+        if leap && m > 6 {
+            doy += 1;
+        }
+
+        Ok(Gregorian::days_before_year(y) + doy)
+    }
+
+    fn rd_to_ymd(rd: i64) -> (i64, i64, i64) {
+        let (year, _, _) = Gregorian::rd_to_ymd(rd);
+        let mut doy = rd - Gregorian::days_before_year(year);
+
+        if Gregorian::is_leap(year) {
+            if doy == Self::LEAP_DAY {
+                return (year, 6, 29);
+            }
+            if doy > Self::LEAP_DAY {
+                doy -= 1;
+            }
+        }
+        if doy == 13 * 28 {
+            return (year, 13, 29);
+        }
+        (year, doy / 28 + 1, doy % 28 + 1)
+    }
+
+    pub const MONTH_NAMES: [&'static str; 13] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "Sol",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+}
+
+impl Calendar for InternationalFixed {
+    type Date = Date;
+    type Variant = Variant;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        let rd = Self::ymd_to_rd(date.year as i64, date.month as i64, date.day as i64)?;
+        Ok(EpochDay(rd))
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        let (y, m, d) = Self::rd_to_ymd(ed.0);
+        Ok(Date {
+            year: y as i32,
+            month: m as u8,
+            day: d as u8,
+        })
+    }
+}
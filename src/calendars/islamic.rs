@@ -1,4 +1,15 @@
-use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+#[cfg(feature = "astro")]
+use crate::astro::{
+    Location, lunar, time,
+    visibility::{self, Criterion},
+};
+use crate::core::{
+    context::{Context, MonthAdjustments},
+    epoch::EpochDay,
+    error::CalError,
+    moment::DayBoundary,
+    traits::{Calendar, CalendarInfo, check_range},
+};
 use crate::util::math::div_floor;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,103 +19,466 @@ pub struct Date {
     pub day: u8,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl Date {
+    /// Checked constructor.
+    ///
+    /// Month lengths depend on the [`Variant`], so this only rejects days
+    /// past the 30th; [`Date::new_in`] checks against a variant.
+    ///
+    /// # Errors
+    /// Returns [`CalError::MonthOutOfRange`] or [`CalError::DayOutOfRange`].
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self, CalError> {
+        if !(1..=12).contains(&month) {
+            return Err(CalError::MonthOutOfRange);
+        }
+        if !(1..=30).contains(&day) {
+            return Err(CalError::DayOutOfRange);
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Checked constructor for variant `var`.
+    ///
+    /// # Errors
+    /// Returns [`CalError::MonthOutOfRange`] or [`CalError::DayOutOfRange`]
+    /// if the month or day does not exist in `year`, otherwise as for
+    /// [`Calendar::to_epoch_day`].
+    pub fn new_in(
+        year: i32,
+        month: u8,
+        day: u8,
+        var: &Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Self, CalError> {
+        let date = Self::new(year, month, day)?;
+        if day > Islamic::days_in_month(year, month, var, ctx)? {
+            return Err(CalError::DayOutOfRange);
+        }
+        Ok(date)
+    }
+
+    /// Lenient constructor for variant `var`: months past Dhu al-Hijjah and
+    /// days past the end of the month roll over into the following ones (and
+    /// below 1, back into the preceding ones), so 1446-12-30 in the civil
+    /// calendar, a common year, is 1447-01-01.
+    ///
+    /// # Errors
+    /// Returns [`CalError::Overflow`] or [`CalError::OutOfRange`] if the
+    /// result is out of range, otherwise as for [`Calendar::to_epoch_day`].
+    pub fn normalized(
+        year: i32,
+        month: i64,
+        day: i64,
+        var: &Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Self, CalError> {
+        let months = month.checked_sub(1).ok_or(CalError::Overflow)?;
+        let first = Self {
+            year: i32::try_from(year as i64 + div_floor(months, 12))
+                .map_err(|_| CalError::Overflow)?,
+            month: (months.rem_euclid(12) + 1) as u8,
+            day: 1,
+        };
+        let start = Islamic::to_epoch_day(&first, var, ctx)?;
+        let ed = day
+            .checked_sub(1)
+            .and_then(|d| start.0.checked_add(d))
+            .ok_or(CalError::Overflow)?;
+        Islamic::from_epoch_day(EpochDay(ed), var, ctx)
+    }
+}
+
+/// Leap years within the 30-year cycle of the tabular calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeapPattern {
+    /// 2, 5, 7, 10, 13, 15, 18, 21, 24, 26, 29
+    Type15,
+    /// 2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29 (the most widespread scheme)
+    Type16,
+    /// 2, 5, 8, 10, 13, 16, 19, 21, 24, 27, 29 (Bohra / Fatimid)
+    Fatimid,
+    /// 2, 5, 8, 11, 13, 16, 19, 21, 24, 27, 30 (Habash al-Hasib, al-Biruni)
+    HabashAlHasib,
+}
+
+impl LeapPattern {
+    /// Offset `k` such that year `y` is leap iff `(11y + k) mod 30 < 11`.
+    #[inline]
+    const fn offset(self) -> i64 {
+        match self {
+            LeapPattern::Type15 => 15,
+            LeapPattern::Type16 => 14,
+            LeapPattern::Fatimid => 11,
+            LeapPattern::HabashAlHasib => 9,
+        }
+    }
+}
+
+/// Day taken as 1 Muharram, 1 AH.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Epoch {
+    /// Thursday 622-07-15 (Julian), the astronomical epoch.
+    Thursday,
+    /// Friday 622-07-16 (Julian), the civil epoch.
+    Friday,
+}
+
+/// Non-exhaustive: the `astro` feature adds variants.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
 pub enum Variant {
-    Civil, // tabular (arithmetical) calendar
+    /// Tabular (arithmetical) calendar: [`LeapPattern::Type16`] with the Friday epoch.
+    #[default]
+    Civil,
+    /// Tabular calendar with any combination of leap pattern and epoch.
+    Tabular { leap: LeapPattern, epoch: Epoch },
+    /// Umm al-Qura calendar of Saudi Arabia, 1300–1600 AH (`tables` feature).
+    UmmAlQura,
+    /// Months start the day after the crescent is predicted to be seen from
+    /// the [`Context`] location (`astro` feature).
+    #[cfg(feature = "astro")]
+    Observational { criterion: Criterion },
+    /// Global unified calendar (Istanbul 2016), followed by Turkey's Diyanet:
+    /// a month starts on the day after the crescent could be seen anywhere on
+    /// Earth before 00:00 UT, with the America exception (`astro` feature).
+    /// Months begin on the same UT date worldwide.
+    ///
+    /// ```
+    /// use calz::calendars::gregorian::{self, Gregorian};
+    /// use calz::calendars::islamic::{self, Islamic, Variant};
+    /// use calz::convert;
+    ///
+    /// // Diyanet began Ramadan 1445 on 2024-03-11
+    /// let ramadan = islamic::Date { year: 1445, month: 9, day: 1 };
+    /// let start =
+    ///     convert::<Islamic, Gregorian>(&ramadan, &Variant::Unified, &Default::default(), None);
+    /// assert_eq!(start.unwrap(), gregorian::Date { year: 2024, month: 3, day: 11 });
+    /// ```
+    #[cfg(feature = "astro")]
+    Unified,
 }
 
+/// Islamic (Hijri) lunar calendar.
+///
+/// # Example
+/// ```
+/// use calz::calendars::gregorian::{self, Gregorian};
+/// use calz::calendars::islamic::{self, Islamic};
+/// use calz::calendars::julian::{self, Julian};
+/// use calz::convert;
+///
+/// // The civil epoch, 1 Muharram 1 AH, is Friday 622-07-16 (Julian)
+/// let epoch = islamic::Date { year: 1, month: 1, day: 1 };
+/// let julian = convert::<Islamic, Julian>(
+///     &epoch,
+///     &islamic::Variant::Civil,
+///     &julian::Variant::Proleptic,
+///     None,
+/// )
+/// .unwrap();
+/// assert_eq!((julian.year, julian.month, julian.day), (622, 7, 16));
+///
+/// let new_year = islamic::Date { year: 1446, month: 1, day: 1 };
+/// let gregorian = convert::<Islamic, Gregorian>(
+///     &new_year,
+///     &islamic::Variant::Civil,
+///     &gregorian::Variant::Proleptic,
+///     None,
+/// )
+/// .unwrap();
+/// assert_eq!((gregorian.year, gregorian.month, gregorian.day), (2024, 7, 8));
+/// ```
 pub struct Islamic;
 
 impl Islamic {
-    // Islamic epoch: 622-07-16 (Julian) = RD 227015
-    const EPOCH: i64 = 227013;
+    // Islamic civil epoch: Friday 622-07-16 (Julian)
+    const EPOCH: i64 = 227014;
 
     #[inline]
-    fn is_leap(y: i64) -> bool {
+    const fn epoch(epoch: Epoch) -> i64 {
+        match epoch {
+            Epoch::Thursday => Self::EPOCH - 1,
+            Epoch::Friday => Self::EPOCH,
+        }
+    }
+
+    #[inline]
+    fn is_leap(y: i64, leap: LeapPattern) -> bool {
         // Leap years in a 30-year cycle occur 11 times
-        ((11 * y + 14) % 30) < 11
+        (11 * y + leap.offset()).rem_euclid(30) < 11
     }
 
-    fn ymd_to_rd(y: i64, m: i64, d: i64) -> Result<i64, CalError> {
+    #[inline]
+    const fn days_before_year(y: i64, leap: LeapPattern) -> i64 {
+        let y1 = y - 1;
+        354 * y1 + div_floor(11 * y1 + leap.offset(), 30)
+    }
+
+    /// Month lengths alternate 30 / 29; the last month has 30 days in leap years.
+    #[inline]
+    fn tabular_month_length(y: i64, m: i64, leap: LeapPattern) -> i64 {
+        if m % 2 == 1 || (m == 12 && Self::is_leap(y, leap)) {
+            30
+        } else {
+            29
+        }
+    }
+
+    #[inline]
+    fn days_before_month(m: i64) -> i64 {
+        29 * (m - 1) + m / 2
+    }
+
+    fn ymd_to_rd(y: i64, m: i64, d: i64, leap: LeapPattern, epoch: Epoch) -> Result<i64, CalError> {
         if !(1..=12).contains(&m) {
             return Err(CalError::InvalidDate);
         }
-        if d < 1 {
+        if d < 1 || d > Self::tabular_month_length(y, m, leap) {
             return Err(CalError::InvalidDate);
         }
 
-        // Month lengths: alternate 30 / 29, last month 29 or 30 in leap year
-        let mdays = match m {
-            1 | 3 | 5 | 7 | 9 | 11 => 30,
-            12 => {
-                if Self::is_leap(y) {
-                    30
-                } else {
-                    29
-                }
-            }
-            _ => 29,
+        let rd = Self::days_before_year(y, leap)
+            .checked_add(Self::epoch(epoch) + Self::days_before_month(m) + d - 1)
+            .ok_or(CalError::Overflow)?;
+        Ok(check_range::<Self>(EpochDay(rd))?.0)
+    }
+
+    fn rd_to_ymd(rd: i64, leap: LeapPattern, epoch: Epoch) -> (i64, i64, i64) {
+        let days = rd - Self::epoch(epoch);
+
+        // Approximate year from the mean year of 10631/30 days, then correct it
+        let mut year = div_floor(30 * days + 10646, 10631);
+        if days < Self::days_before_year(year, leap) {
+            year -= 1;
+        } else if days >= Self::days_before_year(year + 1, leap) {
+            year += 1;
+        }
+        let day_of_year = days - Self::days_before_year(year, leap);
+
+        let mut m = 1;
+        while m < 12 && Self::days_before_month(m + 1) <= day_of_year {
+            m += 1;
+        }
+        (year, m, day_of_year - Self::days_before_month(m) + 1)
+    }
+
+    #[cfg(feature = "tables")]
+    fn umm_al_qura_to_rd(y: i64, m: i64, d: i64) -> Result<i64, CalError> {
+        use crate::tables::umm_al_qura;
+
+        if !(umm_al_qura::FIRST_YEAR..=umm_al_qura::LAST_YEAR).contains(&y) {
+            return Err(CalError::OutOfRange);
+        }
+        let (Some(start), Some(mdays)) = (
+            umm_al_qura::month_start(y, m),
+            umm_al_qura::days_in_month(y, m),
+        ) else {
+            return Err(CalError::InvalidDate);
         };
-        if d > mdays {
+        if d < 1 || d > mdays {
             return Err(CalError::InvalidDate);
         }
+        Ok(start + d - 1)
+    }
 
-        // Days before this year
-        let y1 = y - 1;
-        let days_before_year = 354 * y1 + div_floor(3 + 11 * y1, 30);
-
-        // Day-of-year
-        let mut doy = 0;
-        for mm in 1..m {
-            doy += match mm {
-                1 | 3 | 5 | 7 | 9 | 11 => 30,
-                12 => {
-                    if Self::is_leap(y) {
-                        30
-                    } else {
-                        29
-                    }
-                }
-                _ => 29,
-            };
+    #[cfg(not(feature = "tables"))]
+    fn umm_al_qura_to_rd(_y: i64, _m: i64, _d: i64) -> Result<i64, CalError> {
+        Err(CalError::TableMissing)
+    }
+
+    #[cfg(feature = "tables")]
+    fn umm_al_qura_month_length(y: i64, m: i64) -> Result<i64, CalError> {
+        crate::tables::umm_al_qura::days_in_month(y, m).ok_or(CalError::OutOfRange)
+    }
+
+    #[cfg(not(feature = "tables"))]
+    fn umm_al_qura_month_length(_y: i64, _m: i64) -> Result<i64, CalError> {
+        Err(CalError::TableMissing)
+    }
+
+    #[cfg(feature = "tables")]
+    fn umm_al_qura_from_rd(rd: i64) -> Result<(i64, i64, i64), CalError> {
+        crate::tables::umm_al_qura::rd_to_ymd(rd).ok_or(CalError::OutOfRange)
+    }
+
+    #[cfg(not(feature = "tables"))]
+    fn umm_al_qura_from_rd(_rd: i64) -> Result<(i64, i64, i64), CalError> {
+        Err(CalError::TableMissing)
+    }
+
+    /// Observer and ΔT near day `rd` for the observational variant.
+    #[cfg(feature = "astro")]
+    fn observer(rd: f64, ctx: Option<&Context>) -> Result<(Location, f64), CalError> {
+        let ctx = ctx.ok_or(CalError::NeedsContext)?;
+        let loc = Location::try_from(ctx)?.on_day(EpochDay(rd as i64), Some(ctx))?;
+        Ok((loc, time::delta_t(Some(ctx), rd)))
+    }
+
+    /// Approximate day on which month `m` of year `y` begins.
+    #[cfg(feature = "astro")]
+    #[inline]
+    fn mean_month_start(y: i64, m: i64) -> f64 {
+        Self::EPOCH as f64 + (12 * (y - 1) + m - 1) as f64 * lunar::MEAN_SYNODIC_MONTH
+    }
+
+    /// First day of the `n`-th month since the epoch, as predicted.
+    ///
+    /// `new_month(d)` tells whether a month starts on day `d`.
+    #[cfg(feature = "astro")]
+    fn predicted_month_start(
+        n: i64,
+        dt: f64,
+        new_month: impl Fn(i64) -> bool,
+    ) -> Result<i64, CalError> {
+        let midmonth = Self::EPOCH + (n as f64 * lunar::MEAN_SYNODIC_MONTH).round() as i64 + 14;
+        visibility::month_start_on_or_before(midmonth, dt, new_month)
+    }
+
+    #[cfg(feature = "astro")]
+    fn predicted_to_rd(
+        y: i64,
+        m: i64,
+        d: i64,
+        dt: f64,
+        new_month: impl Fn(i64) -> bool,
+    ) -> Result<i64, CalError> {
+        if !(1..=12).contains(&m) {
+            return Err(CalError::InvalidDate);
         }
-        doy += d - 1;
+        let n = 12 * (y - 1) + m - 1;
+        let start = Self::predicted_month_start(n, dt, &new_month)?;
+        let mdays = Self::predicted_month_start(n + 1, dt, &new_month)? - start;
+        if d < 1 || d > mdays {
+            return Err(CalError::InvalidDate);
+        }
+        Ok(start + d - 1)
+    }
 
-        Ok(Self::EPOCH + days_before_year + doy)
+    #[cfg(feature = "astro")]
+    fn predicted_from_rd(
+        rd: i64,
+        dt: f64,
+        new_month: impl Fn(i64) -> bool,
+    ) -> Result<(i64, i64, i64), CalError> {
+        let start = visibility::month_start_on_or_before(rd, dt, new_month)?;
+        let n = ((start - Self::EPOCH) as f64 / lunar::MEAN_SYNODIC_MONTH).round() as i64;
+        Ok((div_floor(n, 12) + 1, n.rem_euclid(12) + 1, rd - start + 1))
     }
 
-    fn rd_to_ymd(rd: i64) -> (i64, i64, i64) {
-        let days = rd - Self::EPOCH;
+    fn base_to_rd(
+        y: i64,
+        m: i64,
+        d: i64,
+        var: &Variant,
+        ctx: Option<&Context>,
+    ) -> Result<i64, CalError> {
+        #[cfg(not(feature = "astro"))]
+        let _ = ctx;
+        match *var {
+            Variant::Civil => Self::ymd_to_rd(y, m, d, LeapPattern::Type16, Epoch::Friday),
+            Variant::Tabular { leap, epoch } => Self::ymd_to_rd(y, m, d, leap, epoch),
+            Variant::UmmAlQura => Self::umm_al_qura_to_rd(y, m, d),
+            #[cfg(feature = "astro")]
+            Variant::Observational { criterion } => {
+                let (loc, dt) = Self::observer(Self::mean_month_start(y, m), ctx)?;
+                Self::predicted_to_rd(y, m, d, dt, |day| {
+                    visibility::is_visible(criterion, day - 1, dt, &loc)
+                })
+            }
+            #[cfg(feature = "astro")]
+            Variant::Unified => {
+                let dt = time::delta_t(ctx, Self::mean_month_start(y, m));
+                Self::predicted_to_rd(y, m, d, dt, |day| visibility::unified_month_starts(day, dt))
+            }
+        }
+    }
 
-        // Approximate year
-        let year = div_floor(30 * days + 10646, 10631);
-        let start_of_year = 354 * (year - 1) + div_floor(3 + 11 * (year - 1), 30);
-        let day_of_year = days - start_of_year;
+    fn base_from_rd(
+        rd: i64,
+        var: &Variant,
+        ctx: Option<&Context>,
+    ) -> Result<(i64, i64, i64), CalError> {
+        #[cfg(not(feature = "astro"))]
+        let _ = ctx;
+        match *var {
+            Variant::Civil => Ok(Self::rd_to_ymd(rd, LeapPattern::Type16, Epoch::Friday)),
+            Variant::Tabular { leap, epoch } => Ok(Self::rd_to_ymd(rd, leap, epoch)),
+            Variant::UmmAlQura => Self::umm_al_qura_from_rd(rd),
+            #[cfg(feature = "astro")]
+            Variant::Observational { criterion } => {
+                let (loc, dt) = Self::observer(rd as f64, ctx)?;
+                Self::predicted_from_rd(rd, dt, |day| {
+                    visibility::is_visible(criterion, day - 1, dt, &loc)
+                })
+            }
+            #[cfg(feature = "astro")]
+            Variant::Unified => {
+                let dt = time::delta_t(ctx, rd as f64);
+                Self::predicted_from_rd(rd, dt, |day| visibility::unified_month_starts(day, dt))
+            }
+        }
+    }
 
-        // Find month
-        let mut m = 1;
-        let mut days_passed = 0;
-        loop {
-            let mdays = match m {
-                1 | 3 | 5 | 7 | 9 | 11 => 30,
-                12 => {
-                    if Self::is_leap(year) {
-                        30
-                    } else {
-                        29
-                    }
-                }
-                _ => 29,
-            };
-            if day_of_year < days_passed + mdays {
-                let d = day_of_year - days_passed + 1;
-                return (year, m, d);
+    /// Month-start corrections from the context, if there are any.
+    #[inline]
+    fn adjustments(ctx: Option<&Context>) -> Option<&MonthAdjustments> {
+        ctx.map(|c| &c.month_adjustments).filter(|a| !a.is_empty())
+    }
+
+    /// First day of month `m` of year `y` after applying `adj`.
+    fn month_start(
+        y: i64,
+        m: i64,
+        var: &Variant,
+        ctx: Option<&Context>,
+        adj: &MonthAdjustments,
+    ) -> Result<i64, CalError> {
+        let year = i32::try_from(y).map_err(|_| CalError::OutOfRange)?;
+        Self::base_to_rd(y, m, 1, var, ctx)?
+            .checked_add(adj.get(year, m as u8))
+            .ok_or(CalError::Overflow)
+    }
+
+    /// Days in month `m` of year `y`, after any adjustments from the context.
+    ///
+    /// Adjustments giving a month other than 29 or 30 days are rejected with
+    /// [`CalError::InvalidContext`].
+    fn month_length(y: i64, m: i64, var: &Variant, ctx: Option<&Context>) -> Result<i64, CalError> {
+        if !(1..=12).contains(&m) {
+            return Err(CalError::InvalidDate);
+        }
+        let (ny, nm) = Self::next_month(y, m);
+        if let Some(adj) = Self::adjustments(ctx) {
+            let start = Self::month_start(y, m, var, ctx, adj)?;
+            let days = Self::month_start(ny, nm, var, ctx, adj)?
+                .checked_sub(start)
+                .ok_or(CalError::InvalidContext)?;
+            if !(29..=30).contains(&days) {
+                return Err(CalError::InvalidContext);
+            }
+            return Ok(days);
+        }
+        match *var {
+            Variant::Civil => Ok(Self::tabular_month_length(y, m, LeapPattern::Type16)),
+            Variant::Tabular { leap, .. } => Ok(Self::tabular_month_length(y, m, leap)),
+            Variant::UmmAlQura => Self::umm_al_qura_month_length(y, m),
+            #[cfg(feature = "astro")]
+            Variant::Observational { .. } | Variant::Unified => {
+                Ok(Self::base_to_rd(ny, nm, 1, var, ctx)? - Self::base_to_rd(y, m, 1, var, ctx)?)
             }
-            days_passed += mdays;
-            m += 1;
         }
     }
 
+    #[inline]
+    fn next_month(y: i64, m: i64) -> (i64, i64) {
+        if m == 12 { (y + 1, 1) } else { (y, m + 1) }
+    }
+
+    #[inline]
+    fn prev_month(y: i64, m: i64) -> (i64, i64) {
+        if m == 1 { (y - 1, 12) } else { (y, m - 1) }
+    }
+
     // Month names (Hijri)
     pub const MONTH_NAMES: [&'static str; 12] = [
         "Muharram",
@@ -140,25 +514,136 @@ impl Calendar for Islamic {
     type Date = Date;
     type Variant = Variant;
 
+    const DAY_BOUNDARY: DayBoundary = DayBoundary::Sunset;
+
+    /// 1 Muharram of year `i32::MIN + 1` in the civil calendar; other
+    /// variants may place it in the year before.
+    const MIN: EpochDay =
+        EpochDay(Self::EPOCH + Self::days_before_year(i32::MIN as i64 + 1, LeapPattern::Type16));
+    /// Last day of year `i32::MAX - 1` in the civil calendar; other variants
+    /// may place it in the year after.
+    const MAX: EpochDay =
+        EpochDay(Self::EPOCH + Self::days_before_year(i32::MAX as i64, LeapPattern::Type16) - 1);
+
     fn to_epoch_day(
         date: &Self::Date,
-        _var: &Self::Variant,
-        _ctx: Option<&Context>,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
     ) -> Result<EpochDay, CalError> {
-        let rd = Self::ymd_to_rd(date.year as i64, date.month as i64, date.day as i64)?;
-        Ok(EpochDay(rd))
+        let (y, m, d) = (date.year as i64, date.month as i64, date.day as i64);
+        let rd = match Self::adjustments(ctx) {
+            None => Self::base_to_rd(y, m, d, var, ctx)?,
+            Some(adj) => {
+                let start = Self::month_start(y, m, var, ctx, adj)?;
+                if d < 1 || d > Self::month_length(y, m, var, ctx)? {
+                    return Err(CalError::InvalidDate);
+                }
+                start + d - 1
+            }
+        };
+        check_range::<Self>(EpochDay(rd))
     }
 
     fn from_epoch_day(
         ed: EpochDay,
-        _var: &Self::Variant,
-        _ctx: Option<&Context>,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
     ) -> Result<Self::Date, CalError> {
-        let (y, m, d) = Self::rd_to_ymd(ed.0);
+        check_range::<Self>(ed)?;
+        let (mut y, mut m, mut d) = Self::base_from_rd(ed.0, var, ctx)?;
+
+        if let Some(adj) = Self::adjustments(ctx) {
+            // Step to the neighbouring month if a shifted start moved the boundary
+            let mut start = Self::month_start(y, m, var, ctx, adj)?;
+            while ed.0 < start {
+                (y, m) = Self::prev_month(y, m);
+                start = Self::month_start(y, m, var, ctx, adj)?;
+            }
+            loop {
+                let (ny, nm) = Self::next_month(y, m);
+                let next = Self::month_start(ny, nm, var, ctx, adj)?;
+                if ed.0 < next {
+                    break;
+                }
+                (y, m, start) = (ny, nm, next);
+            }
+            d = ed.0 - start + 1;
+            // Also rejects adjustments that stretched or squeezed the month
+            Self::month_length(y, m, var, ctx)?;
+        }
+
         Ok(Date {
-            year: y as i32,
+            year: i32::try_from(y).map_err(|_| CalError::OutOfRange)?,
             month: m as u8,
             day: d as u8,
         })
     }
 }
+
+impl CalendarInfo for Islamic {
+    /// A leap year has 355 days; observed and tabulated years may also have 353.
+    fn is_leap_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<bool, CalError> {
+        Ok(Self::days_in_year(year, var, ctx)? == 355)
+    }
+
+    fn months_in_year(
+        _year: i32,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<u8, CalError> {
+        Ok(12)
+    }
+
+    fn days_in_month(
+        year: i32,
+        month: u8,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u8, CalError> {
+        let days = Self::month_length(year as i64, month as i64, var, ctx)?;
+        u8::try_from(days).map_err(|_| CalError::OutOfRange)
+    }
+
+    fn first_day_of_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        Self::to_epoch_day(
+            &Date {
+                year,
+                month: 1,
+                day: 1,
+            },
+            var,
+            ctx,
+        )
+    }
+
+    // Summed by month, so the last year of a table has a length too
+    fn days_in_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u16, CalError> {
+        (1..=12).try_fold(0u16, |days, m| {
+            let month = u16::try_from(Self::month_length(year as i64, m, var, ctx)?)
+                .map_err(|_| CalError::OutOfRange)?;
+            days.checked_add(month).ok_or(CalError::OutOfRange)
+        })
+    }
+
+    fn day_of_year(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u16, CalError> {
+        let day = Self::to_epoch_day(date, var, ctx)?;
+        let first = Self::first_day_of_year(date.year, var, ctx)?;
+        u16::try_from(day.0 - first.0 + 1).map_err(|_| CalError::OutOfRange)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+use crate::util::math::div_floor;
+
+/// A Julian calendar date.
+///
+/// Years use astronomical numbering: year `0` is 1 BC, year `-1` is 2 BC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Variant {
+    #[default]
+    Proleptic,
+}
+
+pub struct Julian;
+
+impl Julian {
+    // Julian 0001-01-01 = Gregorian 0000-12-30
+    const EPOCH: i64 = -2;
+
+    #[inline]
+    pub(crate) fn is_leap(y: i64) -> bool {
+        y.rem_euclid(4) == 0
+    }
+
+    #[inline]
+    pub(crate) fn days_in_month(y: i64, m: i64) -> i64 {
+        match m {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            _ if Self::is_leap(y) => 29,
+            _ => 28,
+        }
+    }
+
+    /// Days before month in a non-leap year.
+    #[inline]
+    fn doy_prefix(month: i64, leap: bool) -> i64 {
+        const CUM: [i64; 13] = [0, 0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+        CUM[month as usize] + if leap && month > 2 { 1 } else { 0 }
+    }
+
+    pub(crate) fn ymd_to_rd(y: i64, m: i64, d: i64) -> Result<i64, CalError> {
+        if !(1..=12).contains(&m) {
+            return Err(CalError::InvalidDate);
+        }
+        if d < 1 || d > Self::days_in_month(y, m) {
+            return Err(CalError::InvalidDate);
+        }
+
+        let y1 = y - 1;
+        let days_before_year = 365 * y1 + div_floor(y1, 4);
+        let doy = Self::doy_prefix(m, Self::is_leap(y)) + d - 1;
+
+        Ok(Self::EPOCH + days_before_year + doy)
+    }
+
+    pub(crate) fn rd_to_ymd(rd: i64) -> (i64, i64, i64) {
+        let days = rd - Self::EPOCH;
+
+        // 1461 days per 4-year cycle; the leap year closes the cycle
+        let year = div_floor(4 * days + 1464, 1461);
+        let day_of_year = days - (365 * (year - 1) + div_floor(year - 1, 4));
+
+        let leap = Self::is_leap(year);
+        let mut m = 1;
+        while m < 12 && Self::doy_prefix(m + 1, leap) <= day_of_year {
+            m += 1;
+        }
+        (year, m, day_of_year - Self::doy_prefix(m, leap) + 1)
+    }
+}
+
+impl Calendar for Julian {
+    type Date = Date;
+    type Variant = Variant;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        let rd = Self::ymd_to_rd(date.year as i64, date.month as i64, date.day as i64)?;
+        Ok(EpochDay(rd))
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        let (y, m, d) = Self::rd_to_ymd(ed.0);
+        Ok(Date {
+            year: y as i32,
+            month: m as u8,
+            day: d as u8,
+        })
+    }
+}
@@ -1,3 +1,13 @@
+pub mod any;
+#[cfg(feature = "astro")]
+pub mod babylonian;
 pub mod coptic;
+pub mod fiscal;
 pub mod gregorian;
+pub mod icelandic;
+pub mod international_fixed;
 pub mod islamic;
+pub mod julian;
+pub mod persian;
+pub mod roman;
+pub mod symmetry454;
@@ -0,0 +1,278 @@
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+use crate::util::math::div_floor;
+
+/// A Solar Hijri date, counted in the Hijri era (AP).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Year counted in `era`.
+    pub fn era_year(&self, era: Era) -> i64 {
+        self.year as i64 + era.offset()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Variant {
+    /// 33-year cycle of 8 leap years, which follows the official (astronomical)
+    /// Iranian calendar over the current centuries.
+    #[default]
+    Arithmetic,
+}
+
+/// Eras in which Solar Hijri years are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Era {
+    /// Hijri era, used in Iran and Afghanistan.
+    Hijri,
+    /// Kurdish (Median) era, from the fall of Nineveh: 1403 AP = 2724.
+    Kurdish,
+    /// Imperial (Shahanshahi) era of Iran, 1976–1978: 1355 AP = 2535.
+    Imperial,
+}
+
+impl Era {
+    /// Years to add to a Solar Hijri year.
+    #[inline]
+    pub fn offset(self) -> i64 {
+        match self {
+            Era::Hijri => 0,
+            Era::Kurdish => 1321,
+            Era::Imperial => 1180,
+        }
+    }
+}
+
+/// Regional naming of the Solar Hijri months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// Iran: Farvardin, Ordibehesht, …
+    Iranian,
+    /// Afghanistan, Dari: zodiac names Hamal, Sawr, …
+    Dari,
+    /// Afghanistan, Pashto: Wray, Ghwayai, …
+    Pashto,
+    /// Kurdistan, Sorani: Xakelêwe, Gullan, …
+    Kurdish,
+}
+
+impl Locale {
+    /// Month names in the locale's own script.
+    pub fn month_names(self) -> &'static [&'static str; 12] {
+        match self {
+            Locale::Iranian => &Persian::MONTH_NAMES_PERSIAN,
+            Locale::Dari => &Persian::MONTH_NAMES_DARI,
+            Locale::Pashto => &Persian::MONTH_NAMES_PASHTO,
+            Locale::Kurdish => &Persian::MONTH_NAMES_KURDISH,
+        }
+    }
+
+    /// Era in which the locale counts years.
+    #[inline]
+    pub fn era(self) -> Era {
+        match self {
+            Locale::Kurdish => Era::Kurdish,
+            _ => Era::Hijri,
+        }
+    }
+}
+
+/// Solar Hijri calendar of Iran and Afghanistan.
+///
+/// The year starts at Nowruz, near the March equinox. The first six months
+/// have 31 days, the next five 30, and Esfand 29 or 30.
+pub struct Persian;
+
+impl Persian {
+    // Aligns the 33-year rule with the official calendar (1 Farvardin 1403 = 2024-03-20)
+    const EPOCH: i64 = 226_894;
+
+    #[inline]
+    fn is_leap(y: i64) -> bool {
+        // 8 leap years in a 33-year cycle
+        (25 * y + 11).rem_euclid(33) < 8
+    }
+
+    #[inline]
+    fn days_before_year(y: i64) -> i64 {
+        365 * (y - 1) + div_floor(8 * y + 21, 33)
+    }
+
+    #[inline]
+    fn days_before_month(m: i64) -> i64 {
+        if m <= 7 {
+            31 * (m - 1)
+        } else {
+            30 * (m - 1) + 6
+        }
+    }
+
+    #[inline]
+    fn days_in_month(y: i64, m: i64) -> i64 {
+        match m {
+            1..=6 => 31,
+            12 if !Self::is_leap(y) => 29,
+            _ => 30,
+        }
+    }
+
+    fn ymd_to_rd(y: i64, m: i64, d: i64) -> Result<i64, CalError> {
+        if !(1..=12).contains(&m) {
+            return Err(CalError::InvalidDate);
+        }
+        if d < 1 || d > Self::days_in_month(y, m) {
+            return Err(CalError::InvalidDate);
+        }
+        Ok(Self::EPOCH + Self::days_before_year(y) + Self::days_before_month(m) + d - 1)
+    }
+
+    fn rd_to_ymd(rd: i64) -> (i64, i64, i64) {
+        let days = rd - Self::EPOCH;
+        // 12053 days per 33-year cycle
+        let year = div_floor(33 * days + 3, 12053) + 1;
+        let day_of_year = days - Self::days_before_year(year);
+
+        let mut m = 1;
+        while m < 12 && Self::days_before_month(m + 1) <= day_of_year {
+            m += 1;
+        }
+        (year, m, day_of_year - Self::days_before_month(m) + 1)
+    }
+
+    pub const MONTH_NAMES: [&'static str; 12] = [
+        "Farvardin",
+        "Ordibehesht",
+        "Khordad",
+        "Tir",
+        "Mordad",
+        "Shahrivar",
+        "Mehr",
+        "Aban",
+        "Azar",
+        "Dey",
+        "Bahman",
+        "Esfand",
+    ];
+    pub const MONTH_NAMES_PERSIAN: [&'static str; 12] = [
+        "فروردین",
+        "اردیبهشت",
+        "خرداد",
+        "تیر",
+        "مرداد",
+        "شهریور",
+        "مهر",
+        "آبان",
+        "آذر",
+        "دی",
+        "بهمن",
+        "اسفند",
+    ];
+    // Afghan names follow the signs of the zodiac
+    pub const MONTH_NAMES_AFGHAN: [&'static str; 12] = [
+        "Hamal", "Sawr", "Jawza", "Saratan", "Asad", "Sunbula", "Mizan", "Aqrab", "Qaws", "Jadi",
+        "Dalw", "Hut",
+    ];
+    pub const MONTH_NAMES_DARI: [&'static str; 12] = [
+        "حمل",
+        "ثور",
+        "جوزا",
+        "سرطان",
+        "اسد",
+        "سنبله",
+        "میزان",
+        "عقرب",
+        "قوس",
+        "جدی",
+        "دلو",
+        "حوت",
+    ];
+    pub const MONTH_NAMES_PASHTO: [&'static str; 12] = [
+        "وری",
+        "غویی",
+        "غبرگولی",
+        "چنګاښ",
+        "زمری",
+        "وږی",
+        "تله",
+        "لړم",
+        "لیندۍ",
+        "مرغومی",
+        "سلواغه",
+        "کب",
+    ];
+    pub const MONTH_NAMES_PASHTO_LATIN: [&'static str; 12] = [
+        "Wray",
+        "Ghwayai",
+        "Ghbargolai",
+        "Changakh",
+        "Zmaray",
+        "Wagay",
+        "Tala",
+        "Larm",
+        "Lindai",
+        "Marghumay",
+        "Salwagha",
+        "Kab",
+    ];
+    // Sorani
+    pub const MONTH_NAMES_KURDISH: [&'static str; 12] = [
+        "خاکەلێوە",
+        "گوڵان",
+        "جۆزەردان",
+        "پووشپەڕ",
+        "گەلاوێژ",
+        "خەرمانان",
+        "ڕەزبەر",
+        "گەڵاڕێزان",
+        "سەرماوەز",
+        "بەفرانبار",
+        "ڕێبەندان",
+        "ڕەشەمە",
+    ];
+    // Sorani in the Kurdish Latin alphabet
+    pub const MONTH_NAMES_KURDISH_LATIN: [&'static str; 12] = [
+        "Xakelêwe",
+        "Gullan",
+        "Cozerdan",
+        "Pûşper",
+        "Gelawêj",
+        "Xermanan",
+        "Rezber",
+        "Gelarêzan",
+        "Sermawez",
+        "Befranbar",
+        "Rêbendan",
+        "Reşeme",
+    ];
+}
+
+impl Calendar for Persian {
+    type Date = Date;
+    type Variant = Variant;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        let rd = Self::ymd_to_rd(date.year as i64, date.month as i64, date.day as i64)?;
+        Ok(EpochDay(rd))
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        let (y, m, d) = Self::rd_to_ymd(ed.0);
+        Ok(Date {
+            year: y as i32,
+            month: m as u8,
+            day: d as u8,
+        })
+    }
+}
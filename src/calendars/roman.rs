@@ -0,0 +1,240 @@
+use std::fmt;
+
+use crate::calendars::julian::Julian;
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+
+/// The three fixed reference days of each Roman month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// First day of the month.
+    Kalends,
+    /// 5th, or 7th in March, May, July and October.
+    Nones,
+    /// 13th, or 15th in March, May, July and October.
+    Ides,
+}
+
+/// A date in Roman reckoning, counted inclusively backwards to the next event.
+///
+/// - `year`, `month`: Julian year (astronomical numbering) and month of the event
+/// - `count`: `1` is the event itself, `2` is *pridie*, `n ≥ 3` is *ante diem n*
+/// - `leap`: `true` only for the second (bissextile) *a.d. VI Kal. Mart.*
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub event: Event,
+    pub count: u8,
+    pub leap: bool,
+}
+
+impl Date {
+    /// Year counted *ab urbe condita* (from the founding of Rome, 753 BC).
+    ///
+    /// This is the year of the day itself, so the days counted down to the
+    /// Kalends of January still belong to the previous year.
+    pub fn auc_year(&self) -> i64 {
+        let year = if self.month == 1 && self.event == Event::Kalends && self.count > 1 {
+            self.year as i64 - 1
+        } else {
+            self.year as i64
+        };
+        Roman::auc_year(year)
+    }
+}
+
+/// Formats the date with the traditional Latin abbreviations,
+/// e.g. `a.d. III Id. Mart. MMDCCLXXVIII AUC`.
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.count, self.leap) {
+            (1, _) => {}
+            (2, _) => f.write_str("prid. ")?,
+            (n, true) => write!(f, "a.d. bis {} ", Roman::numeral(n as i64))?,
+            (n, false) => write!(f, "a.d. {} ", Roman::numeral(n as i64))?,
+        }
+        let event = match self.event {
+            Event::Kalends => "Kal.",
+            Event::Nones => "Non.",
+            Event::Ides => "Id.",
+        };
+        let month = Roman::MONTH_ABBREVIATIONS
+            .get((self.month as usize).wrapping_sub(1))
+            .copied()
+            .unwrap_or("?");
+        write!(f, "{event} {month} ")?;
+
+        let auc = self.auc_year();
+        if (1..4000).contains(&auc) {
+            write!(f, "{} AUC", Roman::numeral(auc))
+        } else {
+            write!(f, "{auc} AUC")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Variant {
+    /// Roman names laid over the proleptic Julian calendar.
+    #[default]
+    Julian,
+}
+
+/// Roman calendar with Kalends, Nones and Ides reckoning.
+pub struct Roman;
+
+impl Roman {
+    /// Julian year of the founding of Rome (753 BC, astronomical).
+    const YEAR_ROME_FOUNDED: i64 = -752;
+
+    #[inline]
+    fn auc_year(y: i64) -> i64 {
+        y - Self::YEAR_ROME_FOUNDED + 1
+    }
+
+    #[inline]
+    fn nones_of_month(m: i64) -> i64 {
+        if matches!(m, 3 | 5 | 7 | 10) { 7 } else { 5 }
+    }
+
+    #[inline]
+    fn ides_of_month(m: i64) -> i64 {
+        Self::nones_of_month(m) + 8
+    }
+
+    fn roman_to_rd(date: &Date) -> Result<i64, CalError> {
+        let (y, m, count) = (date.year as i64, date.month as i64, date.count as i64);
+        if !(1..=12).contains(&m) || count < 1 {
+            return Err(CalError::InvalidDate);
+        }
+
+        let day = match date.event {
+            Event::Kalends => 1,
+            Event::Nones => Self::nones_of_month(m),
+            Event::Ides => Self::ides_of_month(m),
+        };
+        let base = Julian::ymd_to_rd(y, m, day)?;
+
+        // In leap years a.d. VI Kal. Mart. is doubled, so counts 6..=16 before
+        // the March Kalends are one day closer than usual.
+        let bissextile = Julian::is_leap(y)
+            && m == 3
+            && date.event == Event::Kalends
+            && (6..=16).contains(&count);
+        let rd = base - count + if bissextile { 0 } else { 1 } + if date.leap { 1 } else { 0 };
+
+        // Reject counts that reach past the previous event, or misplaced leap flags
+        if Self::rd_to_roman(rd) != *date {
+            return Err(CalError::InvalidDate);
+        }
+        Ok(rd)
+    }
+
+    fn rd_to_roman(rd: i64) -> Date {
+        let (year, month, day) = Julian::rd_to_ymd(rd);
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+
+        let nones = Self::nones_of_month(month);
+        let ides = Self::ides_of_month(month);
+        let (y, m, event, count, leap) = if day == 1 {
+            (year, month, Event::Kalends, 1, false)
+        } else if day <= nones {
+            (year, month, Event::Nones, nones - day + 1, false)
+        } else if day <= ides {
+            (year, month, Event::Ides, ides - day + 1, false)
+        } else if month != 2 || !Julian::is_leap(year) {
+            let kalends = Julian::days_in_month(year, month) + 1;
+            (
+                next_year,
+                next_month,
+                Event::Kalends,
+                kalends - day + 1,
+                false,
+            )
+        } else if day < 25 {
+            (year, 3, Event::Kalends, 30 - day, false)
+        } else {
+            (year, 3, Event::Kalends, 31 - day, day == 25)
+        };
+
+        Date {
+            year: y as i32,
+            month: m as u8,
+            event,
+            count: count as u8,
+            leap,
+        }
+    }
+
+    /// Roman numeral for `1..4000`.
+    fn numeral(mut n: i64) -> String {
+        const TABLE: [(i64, &str); 13] = [
+            (1000, "M"),
+            (900, "CM"),
+            (500, "D"),
+            (400, "CD"),
+            (100, "C"),
+            (90, "XC"),
+            (50, "L"),
+            (40, "XL"),
+            (10, "X"),
+            (9, "IX"),
+            (5, "V"),
+            (4, "IV"),
+            (1, "I"),
+        ];
+        let mut out = String::new();
+        for (value, symbol) in TABLE {
+            while n >= value {
+                out.push_str(symbol);
+                n -= value;
+            }
+        }
+        out
+    }
+
+    // Month adjectives as abbreviated in dates (Kal. Ian., Id. Mart., ...)
+    pub const MONTH_ABBREVIATIONS: [&'static str; 12] = [
+        "Ian.", "Feb.", "Mart.", "Apr.", "Mai.", "Iun.", "Iul.", "Aug.", "Sept.", "Oct.", "Nov.",
+        "Dec.",
+    ];
+    pub const MONTH_NAMES: [&'static str; 12] = [
+        "Ianuarius",
+        "Februarius",
+        "Martius",
+        "Aprilis",
+        "Maius",
+        "Iunius",
+        "Iulius",
+        "Augustus",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+}
+
+impl Calendar for Roman {
+    type Date = Date;
+    type Variant = Variant;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        Ok(EpochDay(Self::roman_to_rd(date)?))
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        Ok(Self::rd_to_roman(ed.0))
+    }
+}
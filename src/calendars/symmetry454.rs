@@ -0,0 +1,127 @@
+use crate::core::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+use crate::util::math::div_floor;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Variant {
+    #[default]
+    Proleptic,
+}
+
+/// Symmetry454 perennial calendar (Irv Bromberg).
+///
+/// Every quarter has 4-5-4 weeks (28, 35, 28 days) and every month starts on
+/// a Monday. Leap years append a week to December.
+pub struct Symmetry454;
+
+impl Symmetry454 {
+    // Year 1 starts on Monday, 0001-01-01 (Gregorian)
+    const EPOCH: i64 = 0;
+
+    #[inline]
+    fn is_leap(y: i64) -> bool {
+        // 52 leap years in a 293-year cycle
+        (52 * y + 146).rem_euclid(293) < 52
+    }
+
+    #[inline]
+    fn days_before_year(y: i64) -> i64 {
+        let y1 = y - 1;
+        364 * y1 + 7 * div_floor(52 * y1 + 146, 293)
+    }
+
+    #[inline]
+    fn days_in_month(y: i64, m: i64) -> i64 {
+        if m % 3 == 2 || (m == 12 && Self::is_leap(y)) {
+            35
+        } else {
+            28
+        }
+    }
+
+    #[inline]
+    fn days_before_month(m: i64) -> i64 {
+        // 91 days per quarter, split 28 / 35 / 28
+        const IN_QUARTER: [i64; 3] = [0, 28, 63];
+        let q = (m - 1) / 3;
+        91 * q + IN_QUARTER[((m - 1) % 3) as usize]
+    }
+
+    fn ymd_to_rd(y: i64, m: i64, d: i64) -> Result<i64, CalError> {
+        if !(1..=12).contains(&m) {
+            return Err(CalError::InvalidDate);
+        }
+        if d < 1 || d > Self::days_in_month(y, m) {
+            return Err(CalError::InvalidDate);
+        }
+
+        Ok(Self::EPOCH + Self::days_before_year(y) + Self::days_before_month(m) + d - 1)
+    }
+
+    fn rd_to_ymd(rd: i64) -> (i64, i64, i64) {
+        let days = rd - Self::EPOCH;
+
+        // Mean year is 365 + 71/293 days; correct the estimate by at most one year
+        let mut year = div_floor(293 * days, 107016) + 1;
+        if days < Self::days_before_year(year) {
+            year -= 1;
+        } else if days >= Self::days_before_year(year + 1) {
+            year += 1;
+        }
+        let day_of_year = days - Self::days_before_year(year);
+
+        let mut m = 1;
+        while m < 12 && Self::days_before_month(m + 1) <= day_of_year {
+            m += 1;
+        }
+        (year, m, day_of_year - Self::days_before_month(m) + 1)
+    }
+
+    pub const MONTH_NAMES: [&'static str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+}
+
+impl Calendar for Symmetry454 {
+    type Date = Date;
+    type Variant = Variant;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        let rd = Self::ymd_to_rd(date.year as i64, date.month as i64, date.day as i64)?;
+        Ok(EpochDay(rd))
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        _var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        let (y, m, d) = Self::rd_to_ymd(ed.0);
+        Ok(Date {
+            year: y as i32,
+            month: m as u8,
+            day: d as u8,
+        })
+    }
+}
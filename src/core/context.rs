@@ -1,6 +1,6 @@
 use super::epoch::EpochDay;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
 
 /// A generic context struct providing extra information to calendar computations.
 ///
@@ -25,6 +25,11 @@ pub struct Context {
     /// Helps convert astronomical events to local civil dates.
     pub timezone_offset_hours: Option<f64>,
 
+    /// Optional time zone as a POSIX `TZ` rule (e.g. `"CET-1CEST,M3.5.0,M10.5.0/3"`),
+    /// or with the `tz` feature an IANA name (e.g. `"Africa/Cairo"`).
+    /// Takes precedence over `timezone_offset_hours` and follows daylight saving time.
+    pub time_zone: Option<String>,
+
     /// Optional custom epoch override.
     /// Useful for calendars that have a non-standard or adjustable epoch (start date).
     pub custom_epoch: Option<EpochDay>,
@@ -38,6 +43,100 @@ pub struct Context {
     /// Example: Julian→Gregorian transition, stored as `{ "GregorianStart": EpochDay(...) }`.
     #[serde(default)]
     pub cutoffs: HashMap<String, EpochDay>, // Use empty map by default
+
+    /// Corrections to the computed first day of lunar months, keyed by (year, month).
+    /// Lets lunar calendars follow month starts announced by moon sighting.
+    #[serde(default)]
+    pub month_adjustments: MonthAdjustments,
+}
+
+/// Shifts, in days, of the first day of individual lunar months.
+///
+/// Authorities that announce month starts by moon sighting often differ from
+/// arithmetical calendars by a day. Each entry moves the computed first day of
+/// that (year, month) later (positive) or earlier (negative); the lengths of
+/// the month and the one before it change accordingly.
+///
+/// Serialized as a list of `(year, month, days)` triples.
+///
+/// # Example
+/// ```
+/// use calz::core::context::MonthAdjustments;
+///
+/// let mut adj = MonthAdjustments::new();
+/// adj.insert(1445, 9, 1); // Ramadan 1445 began a day later
+/// assert_eq!(adj.get(1445, 9), 1);
+/// assert_eq!(adj.get(1445, 10), 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MonthAdjustments(BTreeMap<(i32, u8), i64>);
+
+impl MonthAdjustments {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the shift for a month, returning the previous one if any.
+    pub fn insert(&mut self, year: i32, month: u8, days: i64) -> Option<i64> {
+        self.0.insert((year, month), days)
+    }
+
+    /// Removes the shift for a month, returning it if any.
+    pub fn remove(&mut self, year: i32, month: u8) -> Option<i64> {
+        self.0.remove(&(year, month))
+    }
+
+    /// Shift for a month; `0` when none is set.
+    pub fn get(&self, year: i32, month: u8) -> i64 {
+        self.0.get(&(year, month)).copied().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether months are 1–12 and the shifts of consecutive months differ by
+    /// at most one day, so no month gains or loses more than a day.
+    ///
+    /// This does not know the calendar's own month lengths: a month of 30
+    /// days can still become 31. Conversions reject that with
+    /// [`CalError::InvalidContext`](super::error::CalError::InvalidContext).
+    pub fn is_consistent(&self) -> bool {
+        self.iter().all(|(y, m, days)| {
+            if !(1..=12).contains(&m) {
+                return false;
+            }
+            let prev = match m {
+                1 => y.checked_sub(1).map_or(0, |y| self.get(y, 12)),
+                _ => self.get(y, m - 1),
+            };
+            let next = match m {
+                12 => y.checked_add(1).map_or(0, |y| self.get(y, 1)),
+                _ => self.get(y, m + 1),
+            };
+            days.abs_diff(prev) <= 1 && days.abs_diff(next) <= 1
+        })
+    }
+
+    /// Iterates over `(year, month, days)` in chronological order.
+    pub fn iter(&self) -> impl Iterator<Item = (i32, u8, i64)> + '_ {
+        self.0.iter().map(|(&(y, m), &d)| (y, m, d))
+    }
+}
+
+impl Serialize for MonthAdjustments {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for MonthAdjustments {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = Vec::<(i32, u8, i64)>::deserialize(deserializer)?;
+        Ok(Self(
+            entries.into_iter().map(|(y, m, d)| ((y, m), d)).collect(),
+        ))
+    }
 }
 
 impl Context {
@@ -53,6 +152,9 @@ impl Context {
         {
             return Err("Latitude out of range".into());
         }
+        if !self.month_adjustments.is_consistent() {
+            return Err("Month adjustments change a month by more than one day".into());
+        }
         Ok(())
     }
 }
@@ -0,0 +1,199 @@
+use super::{context::Context, epoch::EpochDay, error::CalError, traits::Calendar};
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+/// A date in calendar `C`, together with its variant and context.
+///
+/// The date is validated on construction and its [`EpochDay`] kept, so dates
+/// of different calendars compare by the day they denote. The context is
+/// shared, and carried over by [`CalDate::to`].
+///
+/// # Example
+/// ```
+/// use calz::calendars::{coptic::Coptic, gregorian::{self, Gregorian}};
+/// use calz::CalDate;
+///
+/// let new_year = CalDate::<Gregorian>::new(
+///     gregorian::Date { year: 2025, month: 1, day: 1 },
+///     gregorian::Variant::Proleptic,
+///     None,
+/// )
+/// .unwrap();
+/// let coptic = new_year.to::<Coptic>().unwrap();
+/// assert_eq!((coptic.date().year, coptic.date().month, coptic.date().day), (1741, 4, 23));
+/// assert_eq!(coptic, new_year);
+/// assert!(coptic < new_year.add_days(1).unwrap());
+/// ```
+pub struct CalDate<C: Calendar> {
+    date: C::Date,
+    variant: C::Variant,
+    ctx: Option<Arc<Context>>,
+    day: EpochDay,
+}
+
+impl<C: Calendar> CalDate<C> {
+    /// Checks `date` and wraps it with its variant and context.
+    ///
+    /// # Errors
+    /// As for [`Calendar::to_epoch_day`].
+    pub fn new(
+        date: C::Date,
+        variant: C::Variant,
+        ctx: Option<Arc<Context>>,
+    ) -> Result<Self, CalError> {
+        let day = C::to_epoch_day(&date, &variant, ctx.as_deref())?;
+        Ok(Self {
+            date,
+            variant,
+            ctx,
+            day,
+        })
+    }
+
+    /// Date of `day` in calendar `C`.
+    ///
+    /// # Errors
+    /// As for [`Calendar::from_epoch_day`].
+    pub fn from_epoch_day(
+        day: EpochDay,
+        variant: C::Variant,
+        ctx: Option<Arc<Context>>,
+    ) -> Result<Self, CalError> {
+        let date = C::from_epoch_day(day, &variant, ctx.as_deref())?;
+        Ok(Self {
+            date,
+            variant,
+            ctx,
+            day,
+        })
+    }
+
+    /// The native date.
+    #[inline]
+    pub fn date(&self) -> &C::Date {
+        &self.date
+    }
+
+    /// The calendar variant.
+    #[inline]
+    pub fn variant(&self) -> &C::Variant {
+        &self.variant
+    }
+
+    /// The shared context, if any.
+    #[inline]
+    pub fn context(&self) -> Option<&Context> {
+        self.ctx.as_deref()
+    }
+
+    /// Day the date denotes.
+    #[inline]
+    pub fn epoch_day(&self) -> EpochDay {
+        self.day
+    }
+
+    /// The native date, dropping variant and context.
+    #[inline]
+    pub fn into_date(self) -> C::Date {
+        self.date
+    }
+
+    /// The same day in calendar `D`, with its default variant.
+    ///
+    /// # Errors
+    /// As for [`Calendar::from_epoch_day`].
+    pub fn to<D: Calendar>(&self) -> Result<CalDate<D>, CalError>
+    where
+        D::Variant: Default,
+    {
+        self.to_variant::<D>(D::Variant::default())
+    }
+
+    /// The same day in calendar `D` and variant `variant`.
+    ///
+    /// # Errors
+    /// As for [`Calendar::from_epoch_day`].
+    pub fn to_variant<D: Calendar>(&self, variant: D::Variant) -> Result<CalDate<D>, CalError> {
+        CalDate::from_epoch_day(self.day, variant, self.ctx.clone())
+    }
+
+    /// The date `days` later (earlier if negative) in the same calendar.
+    ///
+    /// # Errors
+    /// Returns [`CalError::Overflow`] if the day count overflows, otherwise
+    /// as for [`Calendar::from_epoch_day`].
+    pub fn add_days(&self, days: i64) -> Result<Self, CalError>
+    where
+        C::Variant: Clone,
+    {
+        let day = self.day.0.checked_add(days).ok_or(CalError::Overflow)?;
+        Self::from_epoch_day(EpochDay(day), self.variant.clone(), self.ctx.clone())
+    }
+}
+
+impl<C: Calendar> Clone for CalDate<C>
+where
+    C::Date: Clone,
+    C::Variant: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            date: self.date.clone(),
+            variant: self.variant.clone(),
+            ctx: self.ctx.clone(),
+            day: self.day,
+        }
+    }
+}
+
+impl<C: Calendar> fmt::Debug for CalDate<C>
+where
+    C::Date: fmt::Debug,
+    C::Variant: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CalDate")
+            .field("date", &self.date)
+            .field("variant", &self.variant)
+            .field("day", &self.day)
+            .finish()
+    }
+}
+
+impl<C: Calendar, D: Calendar> PartialEq<CalDate<D>> for CalDate<C> {
+    #[inline]
+    fn eq(&self, other: &CalDate<D>) -> bool {
+        self.day == other.day
+    }
+}
+
+impl<C: Calendar> Eq for CalDate<C> {}
+
+impl<C: Calendar, D: Calendar> PartialOrd<CalDate<D>> for CalDate<C> {
+    #[inline]
+    fn partial_cmp(&self, other: &CalDate<D>) -> Option<Ordering> {
+        Some(self.day.cmp(&other.day))
+    }
+}
+
+impl<C: Calendar> Ord for CalDate<C> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.day.cmp(&other.day)
+    }
+}
+
+impl<C: Calendar> From<CalDate<C>> for EpochDay {
+    #[inline]
+    fn from(date: CalDate<C>) -> Self {
+        date.day
+    }
+}
+
+impl<C: Calendar> From<&CalDate<C>> for EpochDay {
+    #[inline]
+    fn from(date: &CalDate<C>) -> Self {
+        date.day
+    }
+}
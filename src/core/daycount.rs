@@ -0,0 +1,165 @@
+use super::{
+    context::Context,
+    epoch::{
+        DOTNET_EPOCH, EXCEL_1900_EPOCH, EXCEL_1904_EPOCH, EpochDay, JDN_EPOCH, LILIAN_EPOCH,
+        MAC_HFS_EPOCH, MJD_EPOCH, SQL_SERVER_EPOCH, UNIX_EPOCH,
+    },
+    error::CalError,
+    traits::Calendar,
+};
+
+/// .NET ticks (100 ns) per day.
+const TICKS_PER_DAY: i64 = 864_000_000_000;
+
+/// Day counts used by astronomy software, spreadsheets and databases.
+///
+/// Each counts whole days from its own epoch, so it converts to and from
+/// [`EpochDay`] by an offset; Excel 1900 also keeps Lotus 1-2-3's 1900-02-29.
+///
+/// # Example
+/// ```
+/// use calz::core::{daycount::DayCount, epoch::EpochDay};
+///
+/// let day = EpochDay(739_251); // 2025-01-01
+/// assert_eq!(DayCount::JulianDay.from_epoch_day(day).unwrap(), 2_460_677);
+/// assert_eq!(DayCount::Unix.from_epoch_day(day).unwrap(), 20_089);
+/// assert_eq!(DayCount::Excel1900.from_epoch_day(day).unwrap(), 45_658);
+/// assert!(DayCount::Excel1900.to_epoch_day(60).is_err()); // 1900-02-29
+/// assert!(DayCount::JulianDay.to_epoch_day(i64::MIN).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayCount {
+    /// Julian Day Number: the Julian Day beginning at noon of that day.
+    JulianDay,
+    /// Modified Julian Day, from 1858-11-17.
+    ModifiedJulianDay,
+    /// Lilian day, `1` = 1582-10-15.
+    Lilian,
+    /// Days since 1970-01-01.
+    Unix,
+    /// Excel serial number in the 1900 date system, `1` = 1900-01-01.
+    Excel1900,
+    /// Excel serial number in the 1904 date system, `0` = 1904-01-01.
+    Excel1904,
+    /// .NET `DateOnly.DayNumber`, days since 0001-01-01.
+    DotNet,
+    /// SQL Server `datetime` days since 1900-01-01.
+    SqlServer,
+    /// Mac HFS days since 1904-01-01.
+    MacHfs,
+}
+
+impl DayCount {
+    /// Day numbered `0`.
+    ///
+    /// For [`DayCount::Excel1900`] this holds from 1900-03-01 on.
+    pub const fn epoch(self) -> EpochDay {
+        match self {
+            DayCount::JulianDay => JDN_EPOCH,
+            DayCount::ModifiedJulianDay => MJD_EPOCH,
+            DayCount::Lilian => LILIAN_EPOCH,
+            DayCount::Unix => UNIX_EPOCH,
+            DayCount::Excel1900 => EXCEL_1900_EPOCH,
+            DayCount::Excel1904 => EXCEL_1904_EPOCH,
+            DayCount::DotNet => DOTNET_EPOCH,
+            DayCount::SqlServer => SQL_SERVER_EPOCH,
+            DayCount::MacHfs => MAC_HFS_EPOCH,
+        }
+    }
+
+    /// Day number of `ed`.
+    ///
+    /// # Errors
+    /// Returns [`CalError::Overflow`] if the day number does not fit in an
+    /// `i64`.
+    pub fn from_epoch_day(self, ed: EpochDay) -> Result<i64, CalError> {
+        let n = ed.0.checked_sub(self.epoch().0).ok_or(CalError::Overflow)?;
+        // Before the phantom 1900-02-29 (serial 60), serials are one lower
+        if self == DayCount::Excel1900 && n < 61 {
+            n.checked_sub(1).ok_or(CalError::Overflow)
+        } else {
+            Ok(n)
+        }
+    }
+
+    /// Day with number `n`.
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidDate`] for Excel 1900 serial `60`, the
+    /// non-existent 1900-02-29, and [`CalError::Overflow`] if the day does not
+    /// fit in an [`EpochDay`].
+    pub fn to_epoch_day(self, n: i64) -> Result<EpochDay, CalError> {
+        let n = match self {
+            DayCount::Excel1900 if n == 60 => return Err(CalError::InvalidDate),
+            DayCount::Excel1900 if n < 60 => n + 1,
+            _ => n,
+        };
+        self.epoch()
+            .0
+            .checked_add(n)
+            .map(EpochDay)
+            .ok_or(CalError::Overflow)
+    }
+}
+
+/// .NET `DateTime.Ticks` (100 ns units since 0001-01-01) at the start of `ed`.
+///
+/// # Errors
+/// Returns [`CalError::Overflow`] or [`CalError::Underflow`] if the ticks do
+/// not fit in an `i64`.
+pub fn dotnet_ticks(ed: EpochDay) -> Result<i64, CalError> {
+    (ed.0 - DOTNET_EPOCH.0)
+        .checked_mul(TICKS_PER_DAY)
+        .ok_or(if ed.0 < 0 {
+            CalError::Underflow
+        } else {
+            CalError::Overflow
+        })
+}
+
+/// Day containing a .NET `DateTime.Ticks` value.
+pub fn from_dotnet_ticks(ticks: i64) -> EpochDay {
+    EpochDay(DOTNET_EPOCH.0 + ticks.div_euclid(TICKS_PER_DAY))
+}
+
+/// Day counts as a [`Calendar`] whose dates are plain day numbers, so
+/// [`convert`](super::traits::convert) works with them.
+///
+/// # Example
+/// ```
+/// use calz::calendars::gregorian::{self, Gregorian};
+/// use calz::core::daycount::{DayCount, DayNumber};
+/// use calz::convert;
+///
+/// let date = gregorian::Date { year: 2000, month: 1, day: 1 };
+/// let mjd = convert::<Gregorian, DayNumber>(
+///     &date,
+///     &gregorian::Variant::Proleptic,
+///     &DayCount::ModifiedJulianDay,
+///     None,
+/// )
+/// .unwrap();
+/// assert_eq!(mjd, 51_544);
+/// ```
+pub struct DayNumber;
+
+impl Calendar for DayNumber {
+    type Date = i64;
+    type Variant = DayCount;
+
+    fn to_epoch_day(
+        date: &Self::Date,
+        var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError> {
+        var.to_epoch_day(*date)
+    }
+
+    fn from_epoch_day(
+        ed: EpochDay,
+        var: &Self::Variant,
+        _ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        var.from_epoch_day(ed)
+    }
+}
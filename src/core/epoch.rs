@@ -35,3 +35,32 @@ pub struct EpochDay(pub i64);
 ///
 /// This serves as the universal reference point for all `EpochDay` values.
 pub const RD_EPOCH: EpochDay = EpochDay(0);
+
+/// Julian Day Number 0: 4714 BC November 24 (proleptic Gregorian).
+pub const JDN_EPOCH: EpochDay = EpochDay(-1_721_426);
+
+/// Modified Julian Day 0: 1858-11-17.
+pub const MJD_EPOCH: EpochDay = EpochDay(678_575);
+
+/// Lilian day 0: 1582-10-14, the eve of the Gregorian reform (day 1).
+pub const LILIAN_EPOCH: EpochDay = EpochDay(577_734);
+
+/// Unix day 0: 1970-01-01.
+pub const UNIX_EPOCH: EpochDay = EpochDay(719_162);
+
+/// Excel 1900 serial 0 for dates from 1900-03-01: 1899-12-30.
+///
+/// Earlier serials are off by one, as Excel counts a 1900-02-29.
+pub const EXCEL_1900_EPOCH: EpochDay = EpochDay(693_593);
+
+/// Excel 1904 (classic Mac) serial 0: 1904-01-01.
+pub const EXCEL_1904_EPOCH: EpochDay = EpochDay(695_055);
+
+/// .NET `DateTime` / `DateOnly` day 0: 0001-01-01, the same as [`RD_EPOCH`].
+pub const DOTNET_EPOCH: EpochDay = RD_EPOCH;
+
+/// SQL Server `datetime` day 0: 1900-01-01.
+pub const SQL_SERVER_EPOCH: EpochDay = EpochDay(693_595);
+
+/// Mac HFS / HFS+ timestamp 0: 1904-01-01.
+pub const MAC_HFS_EPOCH: EpochDay = EpochDay(695_055);
@@ -16,10 +16,18 @@ pub enum CalError {
     InvalidContext,
     #[error("missing astronomical parameter delta_t")]
     MissingDeltaT,
+    #[error("sun stays above the requested altitude all day")]
+    PolarDay,
+    #[error("sun stays below the requested altitude all day")]
+    PolarNight,
     #[error("table-backed variant not enabled")]
     TableMissing,
     #[error("unknown era")]
     UnknownEra,
+    #[error("unknown calendar")]
+    UnknownCalendar,
+    #[error("date belongs to another calendar")]
+    CalendarMismatch,
     #[error("conversion failed")]
     ConversionFailed,
     #[error("arithmetic overflow")]
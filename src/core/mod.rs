@@ -1,4 +1,10 @@
 pub mod context;
+pub mod date;
+pub mod daycount;
 pub mod epoch;
 pub mod error;
+pub mod moment;
 pub mod traits;
+#[cfg(feature = "tz")]
+pub mod tzif;
+pub mod zone;
@@ -0,0 +1,207 @@
+use super::{
+    context::Context,
+    epoch::{EpochDay, UNIX_EPOCH},
+    error::CalError,
+    zone,
+};
+use serde::{Deserialize, Serialize};
+
+/// Julian Day of the moment `0001-01-01 00:00`.
+pub(crate) const JD_OFFSET: f64 = 1_721_425.5;
+
+/// An instant, as days and fraction of a day on the [`EpochDay`] scale.
+///
+/// `Moment(0.0)` is `0001-01-01 00:00` and `Moment(0.75)` is 18:00 the same
+/// day. Moments are in Universal Time unless a name says otherwise; the day
+/// containing a moment is [`Moment::epoch_day`].
+///
+/// # Example
+/// ```
+/// use calz::core::{epoch::EpochDay, moment::Moment};
+///
+/// // 2000-01-01 12:00 UT
+/// let noon = Moment::from_jd(2_451_545.0);
+/// assert_eq!(noon.epoch_day(), EpochDay(730_119));
+/// assert_eq!(noon.time_of_day(), 0.5);
+/// assert_eq!(Moment::from(EpochDay(730_119)).jd(), 2_451_544.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Moment(pub f64);
+
+impl Moment {
+    /// Midnight at the start of `day`.
+    #[inline]
+    pub fn from_epoch_day(day: EpochDay) -> Self {
+        Moment(day.0 as f64)
+    }
+
+    /// `day` at the given fraction of a day (`0.5` = noon).
+    #[inline]
+    pub fn at(day: EpochDay, time_of_day: f64) -> Self {
+        Moment(day.0 as f64 + time_of_day)
+    }
+
+    /// Day containing the moment.
+    #[inline]
+    pub fn epoch_day(self) -> EpochDay {
+        EpochDay(self.0.floor() as i64)
+    }
+
+    /// Fraction of the day elapsed since midnight, in `[0, 1)`.
+    #[inline]
+    pub fn time_of_day(self) -> f64 {
+        self.0 - self.0.floor()
+    }
+
+    /// Moment of a Julian Day (days from noon, 4713 BC January 1, Julian).
+    #[inline]
+    pub fn from_jd(jd: f64) -> Self {
+        Moment(jd - JD_OFFSET)
+    }
+
+    /// Julian Day of the moment.
+    #[inline]
+    pub fn jd(self) -> f64 {
+        self.0 + JD_OFFSET
+    }
+
+    /// Moment of a Unix timestamp (seconds since 1970-01-01 00:00 UTC).
+    #[inline]
+    pub fn from_unix_seconds(seconds: i64) -> Self {
+        Moment(UNIX_EPOCH.0 as f64 + seconds as f64 / 86_400.0)
+    }
+
+    /// Unix timestamp of the moment, to the nearest second.
+    #[inline]
+    pub fn unix_seconds(self) -> i64 {
+        ((self.0 - UNIX_EPOCH.0 as f64) * 86_400.0).round() as i64
+    }
+
+    /// Local day containing the moment, in the [`Context`] time zone (see
+    /// [`DayBoundary`]).
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidContext`] if `time_zone` cannot be parsed.
+    #[inline]
+    pub fn local_day(self, ctx: Option<&Context>) -> Result<EpochDay, CalError> {
+        DayBoundary::Midnight.day_at(self, ctx)
+    }
+
+    /// Moment shifted by `hours`, e.g. from UT to a local zone.
+    #[inline]
+    pub fn add_hours(self, hours: f64) -> Self {
+        Moment(self.0 + hours / 24.0)
+    }
+
+    /// The same instant in Terrestrial Time, `TT = UT + ΔT`.
+    ///
+    /// ΔT comes from `ctx`, or is estimated.
+    #[cfg(feature = "astro")]
+    pub fn to_tt(self, ctx: Option<&Context>) -> Self {
+        Moment(self.0 + crate::astro::time::delta_t(ctx, self.0))
+    }
+
+    /// Universal Time of an instant given in Terrestrial Time.
+    #[cfg(feature = "astro")]
+    pub fn from_tt(tt: Moment, ctx: Option<&Context>) -> Self {
+        Moment(tt.0 - crate::astro::time::delta_t(ctx, tt.0))
+    }
+}
+
+impl From<EpochDay> for Moment {
+    #[inline]
+    fn from(day: EpochDay) -> Self {
+        Moment::from_epoch_day(day)
+    }
+}
+
+impl From<Moment> for EpochDay {
+    #[inline]
+    fn from(m: Moment) -> Self {
+        m.epoch_day()
+    }
+}
+
+/// Time of day at which a calendar's day begins.
+///
+/// The sunset and sunrise boundaries are computed at the [`Context`]
+/// location with the `astro` feature. Without it, without a location, or
+/// when the Sun does not set or rise that day, they fall back to 18:00 and
+/// 06:00 local time.
+///
+/// Local time follows `time_zone` (with daylight saving time), else
+/// `timezone_offset_hours`, else local mean time at `longitude_deg`, else UT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayBoundary {
+    /// Days begin at local midnight.
+    Midnight,
+    /// Days begin at sunset on the eve of the civil day, as in the Islamic
+    /// and Hebrew calendars.
+    Sunset,
+    /// Days begin at sunrise, as in Hindu calendars.
+    Sunrise,
+}
+
+impl DayBoundary {
+    /// The [`EpochDay`] whose date is current at `m`.
+    ///
+    /// After sunset the date is already that of the next civil day; before
+    /// sunrise it is still that of the previous one.
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidContext`] if `time_zone` cannot be parsed,
+    /// or the location used for sunset or sunrise is out of range.
+    pub fn day_at(self, m: Moment, ctx: Option<&Context>) -> Result<EpochDay, CalError> {
+        let day = zone::local_zone(ctx)?.to_local(m).epoch_day();
+        Ok(match self {
+            DayBoundary::Midnight => day,
+            DayBoundary::Sunset if m >= self.crossing(day, ctx)? => EpochDay(day.0 + 1),
+            DayBoundary::Sunrise if m < self.crossing(day, ctx)? => EpochDay(day.0 - 1),
+            _ => day,
+        })
+    }
+
+    /// Moment (UT) at which the date of `day` begins.
+    ///
+    /// # Errors
+    /// As for [`DayBoundary::day_at`].
+    pub fn start_of(self, day: EpochDay, ctx: Option<&Context>) -> Result<Moment, CalError> {
+        match self {
+            DayBoundary::Sunset => self.crossing(EpochDay(day.0 - 1), ctx),
+            _ => self.crossing(day, ctx),
+        }
+    }
+
+    /// Moment (UT) of the boundary during local day `day`.
+    fn crossing(self, day: EpochDay, ctx: Option<&Context>) -> Result<Moment, CalError> {
+        let fixed = zone::local_zone(ctx)?.to_utc(Moment::at(day, self.fallback_hours() / 24.0));
+        #[cfg(feature = "astro")]
+        {
+            use crate::astro::daylight;
+
+            let sun = match self {
+                DayBoundary::Midnight => return Ok(fixed),
+                DayBoundary::Sunset => daylight::sunset(day, ctx),
+                DayBoundary::Sunrise => daylight::sunrise(day, ctx),
+            };
+            match sun {
+                Err(CalError::NeedsContext | CalError::PolarDay | CalError::PolarNight) => {
+                    Ok(fixed)
+                }
+                sun => sun,
+            }
+        }
+        #[cfg(not(feature = "astro"))]
+        Ok(fixed)
+    }
+
+    /// Local hour of the boundary when the Sun cannot be used.
+    #[inline]
+    fn fallback_hours(self) -> f64 {
+        match self {
+            DayBoundary::Midnight => 0.0,
+            DayBoundary::Sunset => 18.0,
+            DayBoundary::Sunrise => 6.0,
+        }
+    }
+}
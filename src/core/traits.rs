@@ -1,4 +1,9 @@
-use super::{context::Context, epoch::EpochDay, error::CalError};
+use super::{
+    context::Context,
+    epoch::EpochDay,
+    error::CalError,
+    moment::{DayBoundary, Moment},
+};
 
 /// A trait for calendar systems that support conversion to and from a common epoch day.
 ///
@@ -13,6 +18,17 @@ pub trait Calendar {
     type Date;
     type Variant;
 
+    /// When the calendar's day begins; [`DayBoundary::Midnight`] by default.
+    const DAY_BOUNDARY: DayBoundary = DayBoundary::Midnight;
+
+    /// First day the calendar supports; earlier days give
+    /// [`CalError::OutOfRange`]. Unbounded by default.
+    const MIN: EpochDay = EpochDay(i64::MIN);
+
+    /// Last day the calendar supports; later days give
+    /// [`CalError::OutOfRange`]. Unbounded by default.
+    const MAX: EpochDay = EpochDay(i64::MAX);
+
     /// Converts a calendar date to an [`EpochDay`] (days since the reference epoch).
     ///
     /// # Arguments
@@ -42,6 +58,62 @@ pub trait Calendar {
         var: &Self::Variant,
         ctx: Option<&Context>,
     ) -> Result<Self::Date, CalError>;
+
+    /// Converts a calendar date to the [`Moment`] (UT) its day begins, per
+    /// [`Calendar::DAY_BOUNDARY`] at the [`Context`] location.
+    ///
+    /// # Errors
+    /// As for [`Calendar::to_epoch_day`].
+    fn to_moment(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Moment, CalError> {
+        let day = Self::to_epoch_day(date, var, ctx)?;
+        Self::DAY_BOUNDARY.start_of(day, ctx)
+    }
+
+    /// Converts a [`Moment`] (UT) to the calendar date current at that
+    /// instant, per [`Calendar::DAY_BOUNDARY`] at the [`Context`] location.
+    ///
+    /// # Example
+    /// ```
+    /// use calz::calendars::islamic::{Islamic, Variant};
+    /// use calz::core::traits::Calendar;
+    /// use calz::{Context, EpochDay, Moment};
+    ///
+    /// let cairo = Context {
+    ///     latitude_deg: Some(30.0444),
+    ///     longitude_deg: Some(31.2357),
+    ///     timezone_offset_hours: Some(2.0),
+    ///     ..Default::default()
+    /// };
+    /// // Thursday 2025-01-30, 20:00 in Cairo: after sunset, so already Friday's date
+    /// let evening = Moment::at(EpochDay(739_280), 20.0 / 24.0).add_hours(-2.0);
+    /// let date = Islamic::from_moment(evening, &Variant::Civil, Some(&cairo)).unwrap();
+    /// let next_day = Islamic::from_epoch_day(EpochDay(739_281), &Variant::Civil, None).unwrap();
+    /// assert_eq!(date, next_day);
+    /// ```
+    ///
+    /// # Errors
+    /// As for [`Calendar::from_epoch_day`].
+    fn from_moment(
+        m: Moment,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<Self::Date, CalError> {
+        Self::from_epoch_day(Self::DAY_BOUNDARY.day_at(m, ctx)?, var, ctx)
+    }
+}
+
+/// `ed` if it lies within [`Calendar::MIN`]..=[`Calendar::MAX`] of `C`.
+#[inline]
+pub(crate) fn check_range<C: Calendar + ?Sized>(ed: EpochDay) -> Result<EpochDay, CalError> {
+    if (C::MIN.0..=C::MAX.0).contains(&ed.0) {
+        Ok(ed)
+    } else {
+        Err(CalError::OutOfRange)
+    }
 }
 
 /// Converts a date from one calendar system to another via the epoch hub.
@@ -66,3 +138,90 @@ pub fn convert<A: Calendar, B: Calendar>(
     let ed = A::to_epoch_day(a_date, a_var, ctx)?;
     B::from_epoch_day(ed, b_var, ctx)
 }
+
+/// Year and month structure of a [`Calendar`], for validation and date
+/// pickers.
+///
+/// Years and months are numbered as in the calendar's `Date`. Lengths can
+/// depend on the variant and context (e.g. observed Hijri months), hence the
+/// `Result`s.
+///
+/// # Example
+/// ```
+/// use calz::calendars::coptic::{self, Coptic};
+/// use calz::core::traits::CalendarInfo;
+///
+/// let var = coptic::Variant::Proleptic;
+/// assert!(Coptic::is_leap_year(1739, &var, None).unwrap());
+/// assert_eq!(Coptic::months_in_year(1741, &var, None).unwrap(), 13);
+/// assert_eq!(Coptic::days_in_month(1741, 13, &var, None).unwrap(), 5);
+/// assert_eq!(Coptic::days_in_year(1739, &var, None).unwrap(), 366);
+/// ```
+pub trait CalendarInfo: Calendar {
+    /// Whether `year` has the calendar's intercalary day or month.
+    ///
+    /// # Errors
+    /// As for [`Calendar::to_epoch_day`].
+    fn is_leap_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<bool, CalError>;
+
+    /// Number of months in `year`.
+    ///
+    /// # Errors
+    /// As for [`Calendar::to_epoch_day`].
+    fn months_in_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u8, CalError>;
+
+    /// Number of days in month `month` of `year`.
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidDate`] if `month` is not in `year`,
+    /// otherwise as for [`Calendar::to_epoch_day`].
+    fn days_in_month(
+        year: i32,
+        month: u8,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u8, CalError>;
+
+    /// First day of `year`.
+    ///
+    /// # Errors
+    /// As for [`Calendar::to_epoch_day`].
+    fn first_day_of_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<EpochDay, CalError>;
+
+    /// Number of days in `year`.
+    ///
+    /// # Errors
+    /// As for [`Calendar::to_epoch_day`].
+    fn days_in_year(
+        year: i32,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u16, CalError> {
+        let next = year.checked_add(1).ok_or(CalError::Overflow)?;
+        let days =
+            Self::first_day_of_year(next, var, ctx)?.0 - Self::first_day_of_year(year, var, ctx)?.0;
+        u16::try_from(days).map_err(|_| CalError::OutOfRange)
+    }
+
+    /// Day of the year of `date`, `1` for its first day.
+    ///
+    /// # Errors
+    /// As for [`Calendar::to_epoch_day`].
+    fn day_of_year(
+        date: &Self::Date,
+        var: &Self::Variant,
+        ctx: Option<&Context>,
+    ) -> Result<u16, CalError>;
+}
@@ -0,0 +1,169 @@
+//! Reader for TZif files (RFC 8536), the compiled IANA time zone database.
+
+use super::{error::CalError, zone::PosixRule};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Default location of the system time zone database.
+const ZONEINFO: &str = "/usr/share/zoneinfo";
+
+/// Offsets of one IANA zone: its transitions, then the footer rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tzif {
+    /// Unix times of the transitions, ascending.
+    transitions: Vec<i64>,
+    /// Offset in seconds from each transition on.
+    offsets: Vec<i64>,
+    /// Offset before the first transition.
+    initial: i64,
+    /// Rule for instants after the last transition, or for all instants if
+    /// there are none.
+    footer: Option<PosixRule>,
+}
+
+impl Tzif {
+    /// Offset in seconds at Unix time `utc`.
+    pub(crate) fn offset_seconds(&self, utc: i64) -> i64 {
+        let i = self.transitions.partition_point(|&t| t <= utc);
+        match (i, &self.footer) {
+            // With no transitions at all, the footer covers every instant
+            (i, Some(rule)) if i == self.transitions.len() => rule.offset_seconds(utc),
+            (0, _) => self.initial,
+            (i, _) => self.offsets[i - 1],
+        }
+    }
+
+    /// Parses the contents of a TZif file.
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        let mut r = Reader(data);
+        let header = r.header()?;
+        if header.version == 0 {
+            return r.block(&header, 4, None);
+        }
+        // Skip the 32-bit block in favour of the 64-bit one
+        r.take(header.block_len(4))?;
+        let header = r.header()?;
+        let rest = r.0.get(header.block_len(8)..)?;
+        // Footer: "\n<POSIX TZ>\n"; empty means none
+        let footer = match rest {
+            [b'\n', rule @ .., b'\n'] => std::str::from_utf8(rule).ok().and_then(PosixRule::parse),
+            _ => None,
+        };
+        r.block(&header, 8, footer)
+    }
+
+    /// Zone `name` (e.g. `Africa/Cairo`) from the system database, cached.
+    ///
+    /// The database is read from `$TZDIR` if set, else `/usr/share/zoneinfo`.
+    pub(crate) fn named(name: &str) -> Result<Arc<Self>, CalError> {
+        static CACHE: OnceLock<Mutex<HashMap<String, Arc<Tzif>>>> = OnceLock::new();
+
+        let cache = CACHE.get_or_init(Default::default);
+        if let Some(zone) = cache.lock().ok().and_then(|c| c.get(name).cloned()) {
+            return Ok(zone);
+        }
+        // Names are relative paths below the database directory
+        if name.is_empty() || name.starts_with('/') || name.split('/').any(|p| p == "..") {
+            return Err(CalError::InvalidContext);
+        }
+        let dir = std::env::var_os("TZDIR").map_or_else(|| PathBuf::from(ZONEINFO), PathBuf::from);
+        let data = std::fs::read(dir.join(name)).map_err(|_| CalError::InvalidContext)?;
+        let zone = Arc::new(Self::parse(&data).ok_or(CalError::InvalidContext)?);
+        if let Ok(mut c) = cache.lock() {
+            c.insert(name.to_owned(), Arc::clone(&zone));
+        }
+        Ok(zone)
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    /// Length of the data block with `time_size`-byte times.
+    fn block_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// Cursor over a TZif file.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if n > self.0.len() {
+            return None;
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Some(head)
+    }
+
+    fn int(&mut self, size: usize) -> Option<i64> {
+        let bytes = self.take(size)?;
+        Some(match size {
+            4 => i32::from_be_bytes(bytes.try_into().ok()?) as i64,
+            _ => i64::from_be_bytes(bytes.try_into().ok()?),
+        })
+    }
+
+    fn header(&mut self) -> Option<Header> {
+        if self.take(4)? != b"TZif" {
+            return None;
+        }
+        let version = match self.take(1)?[0] {
+            0 => 0,
+            v @ b'2'..=b'9' => v - b'0',
+            _ => return None,
+        };
+        self.take(15)?;
+        let mut count = || self.int(4).and_then(|n| usize::try_from(n).ok());
+        Some(Header {
+            version,
+            isutcnt: count()?,
+            isstdcnt: count()?,
+            leapcnt: count()?,
+            timecnt: count()?,
+            typecnt: count()?,
+            charcnt: count()?,
+        })
+    }
+
+    /// Reads a data block, keeping what offsets need.
+    fn block(&mut self, h: &Header, time_size: usize, footer: Option<PosixRule>) -> Option<Tzif> {
+        let transitions = (0..h.timecnt)
+            .map(|_| self.int(time_size))
+            .collect::<Option<Vec<_>>>()?;
+        let indices = self.take(h.timecnt)?;
+        let types = (0..h.typecnt)
+            .map(|_| {
+                let offset = self.int(4)?;
+                self.take(2)?; // isdst, abbreviation index
+                Some(offset)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let offsets = indices
+            .iter()
+            .map(|&i| types.get(i as usize).copied())
+            .collect::<Option<Vec<_>>>()?;
+        Some(Tzif {
+            transitions,
+            offsets,
+            initial: *types.first()?,
+            footer,
+        })
+    }
+}
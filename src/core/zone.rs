@@ -0,0 +1,380 @@
+#[cfg(feature = "tz")]
+use super::tzif::Tzif;
+use super::{
+    context::Context,
+    epoch::{EpochDay, UNIX_EPOCH},
+    error::CalError,
+    moment::Moment,
+};
+use crate::calendars::gregorian::Gregorian;
+use crate::util::math::div_floor;
+#[cfg(feature = "tz")]
+use std::sync::Arc;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Offset of local time from UT, which may change with daylight saving time.
+///
+/// Non-exhaustive: the `tz` feature adds `TimeZone::Named`.
+///
+/// # Example
+/// ```
+/// use calz::core::zone::TimeZone;
+/// use calz::{EpochDay, Moment};
+///
+/// let new_york = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+/// let winter = Moment::at(EpochDay(739_251), 0.5); // 2025-01-01 12:00 UT
+/// let summer = Moment::at(EpochDay(739_432), 0.5); // 2025-07-01 12:00 UT
+/// assert_eq!(new_york.offset_hours(winter), -5.0);
+/// assert_eq!(new_york.offset_hours(summer), -4.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TimeZone {
+    /// Constant offset in hours, east positive.
+    Fixed(f64),
+    /// POSIX `TZ` rule with optional daylight saving time.
+    Posix(PosixRule),
+    /// Zone from the IANA database, with its full history (`tz` feature).
+    #[cfg(feature = "tz")]
+    Named(Arc<Tzif>),
+}
+
+impl TimeZone {
+    /// Parses a POSIX `TZ` rule such as `"EET-2EEST,M4.5.5/0,M10.5.4/24"`.
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidContext`] if the rule is malformed.
+    pub fn posix(rule: &str) -> Result<Self, CalError> {
+        PosixRule::parse(rule)
+            .map(TimeZone::Posix)
+            .ok_or(CalError::InvalidContext)
+    }
+
+    /// IANA zone such as `"Africa/Cairo"`, read from the TZif file under
+    /// `$TZDIR`, else `/usr/share/zoneinfo` (`tz` feature).
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidContext`] if the zone cannot be found or read.
+    #[cfg(feature = "tz")]
+    pub fn named(name: &str) -> Result<Self, CalError> {
+        Tzif::named(name).map(TimeZone::Named)
+    }
+
+    /// Zone from the contents of a TZif file, e.g. a bundled copy of the
+    /// database (`tz` feature).
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidContext`] if the data is not valid TZif.
+    ///
+    /// # Example
+    /// ```
+    /// use calz::Moment;
+    /// use calz::core::zone::TimeZone;
+    ///
+    /// // No transitions, one type (CET), and the rule in the footer
+    /// let mut data = Vec::new();
+    /// for _ in 0..2 {
+    ///     data.extend(b"TZif2");
+    ///     data.extend([0; 15]);
+    ///     for count in [0u32, 0, 0, 0, 1, 4] {
+    ///         data.extend(count.to_be_bytes());
+    ///     }
+    ///     data.extend(3600i32.to_be_bytes());
+    ///     data.extend(b"\0\0CET\0");
+    /// }
+    /// data.extend(b"\nCET-1CEST,M3.5.0,M10.5.0/3\n");
+    ///
+    /// let zone = TimeZone::from_tzif(&data).unwrap();
+    /// let summer = Moment::from_unix_seconds(1_719_000_000); // 2024-06-21
+    /// assert_eq!(zone.offset_hours(summer), 2.0);
+    /// ```
+    #[cfg(feature = "tz")]
+    pub fn from_tzif(data: &[u8]) -> Result<Self, CalError> {
+        Tzif::parse(data)
+            .map(|tzif| TimeZone::Named(Arc::new(tzif)))
+            .ok_or(CalError::InvalidContext)
+    }
+
+    /// Zone set in `ctx`: `time_zone` if present, else
+    /// `timezone_offset_hours`; `None` if neither is set.
+    ///
+    /// `time_zone` is read as a POSIX rule, or with the `tz` feature as an
+    /// IANA zone name if it is not one.
+    ///
+    /// # Errors
+    /// Returns [`CalError::InvalidContext`] if `time_zone` cannot be resolved.
+    pub fn from_context(ctx: Option<&Context>) -> Result<Option<Self>, CalError> {
+        let Some(ctx) = ctx else {
+            return Ok(None);
+        };
+        if let Some(zone) = &ctx.time_zone {
+            #[cfg(feature = "tz")]
+            if PosixRule::parse(zone).is_none() {
+                return Self::named(zone).map(Some);
+            }
+            return Self::posix(zone).map(Some);
+        }
+        Ok(ctx.timezone_offset_hours.map(TimeZone::Fixed))
+    }
+
+    /// Offset in hours at the UT instant `utc`.
+    pub fn offset_hours(&self, utc: Moment) -> f64 {
+        match self {
+            TimeZone::Fixed(hours) => *hours,
+            TimeZone::Posix(rule) => rule.offset_seconds(utc.unix_seconds()) as f64 / 3600.0,
+            #[cfg(feature = "tz")]
+            TimeZone::Named(tzif) => tzif.offset_seconds(utc.unix_seconds()) as f64 / 3600.0,
+        }
+    }
+
+    /// Local time of the UT instant `utc`.
+    #[inline]
+    pub fn to_local(&self, utc: Moment) -> Moment {
+        utc.add_hours(self.offset_hours(utc))
+    }
+
+    /// UT instant of the local time `local`.
+    ///
+    /// A local time repeated when clocks go back resolves to its first
+    /// occurrence; one skipped when clocks go forward is read with the
+    /// offset in force before the change, and so lands after it.
+    pub fn to_utc(&self, local: Moment) -> Moment {
+        let before = self.offset_hours(local.add_hours(-self.offset_hours(local) - 24.0));
+        let after = self.offset_hours(local.add_hours(-self.offset_hours(local) + 24.0));
+        let offset = [before.max(after), before.min(after)]
+            .into_iter()
+            .find(|&h| self.offset_hours(local.add_hours(-h)) == h)
+            .unwrap_or(before);
+        local.add_hours(-offset)
+    }
+}
+
+/// Local time of `ctx`: its [`TimeZone`], else local mean time at
+/// `longitude_deg`, else UT.
+pub(crate) fn local_zone(ctx: Option<&Context>) -> Result<TimeZone, CalError> {
+    Ok(TimeZone::from_context(ctx)?.unwrap_or_else(|| {
+        TimeZone::Fixed(
+            ctx.and_then(|c| c.longitude_deg)
+                .map_or(0.0, |lon| lon / 15.0),
+        )
+    }))
+}
+
+/// A POSIX `TZ` rule: standard time, and optionally daylight saving time
+/// with its yearly start and end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosixRule {
+    /// Standard offset in seconds, east positive.
+    std_offset: i64,
+    dst: Option<Dst>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Dst {
+    /// Daylight offset in seconds, east positive.
+    offset: i64,
+    start: Transition,
+    end: Transition,
+}
+
+/// Day and local time at which a rule changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Transition {
+    day: RuleDay,
+    /// Seconds after local midnight, possibly negative or past 24 hours.
+    time: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDay {
+    /// `Jn`: day 1–365, never counting February 29.
+    Julian(i64),
+    /// `n`: day 0–365, counting February 29.
+    Ordinal(i64),
+    /// `Mm.w.d`: weekday `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    Weekday { month: i64, week: i64, weekday: i64 },
+}
+
+impl RuleDay {
+    /// Day of the rule in Gregorian year `y`.
+    fn epoch_day(self, y: i64) -> EpochDay {
+        let jan1 = Gregorian::days_before_year(y);
+        let leap = Gregorian::is_leap(y);
+        EpochDay(match self {
+            RuleDay::Julian(n) => jan1 + n - 1 + i64::from(leap && n >= 60),
+            RuleDay::Ordinal(n) => jan1 + n,
+            RuleDay::Weekday {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = jan1 + Gregorian::doy_prefix(month, leap);
+                let next_month = if month == 12 {
+                    Gregorian::days_before_year(y + 1)
+                } else {
+                    jan1 + Gregorian::doy_prefix(month + 1, leap)
+                };
+                // EpochDay(0) is a Monday
+                let day = first + (weekday - (first + 1)).rem_euclid(7) + 7 * (week - 1);
+                if day >= next_month { day - 7 } else { day }
+            }
+        })
+    }
+}
+
+impl PosixRule {
+    /// Offset in seconds at Unix time `utc`.
+    pub(crate) fn offset_seconds(&self, utc: i64) -> i64 {
+        let Some(dst) = self.dst else {
+            return self.std_offset;
+        };
+        let local_day = div_floor(utc + self.std_offset, SECONDS_PER_DAY) + UNIX_EPOCH.0;
+        let (year, _, _) = Gregorian::rd_to_ymd(local_day);
+        let at = |t: Transition, offset: i64| {
+            (t.day.epoch_day(year).0 - UNIX_EPOCH.0) * SECONDS_PER_DAY + t.time - offset
+        };
+        let start = at(dst.start, self.std_offset);
+        let end = at(dst.end, dst.offset);
+        let in_dst = if start < end {
+            start <= utc && utc < end
+        } else {
+            // Southern hemisphere: daylight time spans the new year
+            !(end <= utc && utc < start)
+        };
+        if in_dst { dst.offset } else { self.std_offset }
+    }
+
+    /// Parses `std offset [dst [offset] [,start[/time],end[/time]]]`.
+    pub(crate) fn parse(rule: &str) -> Option<Self> {
+        let mut p = Parser(rule.as_bytes());
+        p.name()?;
+        // POSIX offsets are positive west of Greenwich
+        let std_offset = -p.offset(24)?;
+        if p.0.is_empty() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+        p.name()?;
+        let offset = match p.0.first() {
+            None | Some(b',') => std_offset + 3600,
+            Some(_) => -p.offset(24)?,
+        };
+        // Without rules, US rules are assumed
+        let (start, end) = if p.0.is_empty() {
+            (
+                Transition {
+                    day: RuleDay::Weekday {
+                        month: 3,
+                        week: 2,
+                        weekday: 0,
+                    },
+                    time: 7200,
+                },
+                Transition {
+                    day: RuleDay::Weekday {
+                        month: 11,
+                        week: 1,
+                        weekday: 0,
+                    },
+                    time: 7200,
+                },
+            )
+        } else {
+            p.expect(b',')?;
+            let start = p.transition()?;
+            p.expect(b',')?;
+            (start, p.transition()?)
+        };
+        p.0.is_empty().then_some(Self {
+            std_offset,
+            dst: Some(Dst { offset, start, end }),
+        })
+    }
+}
+
+/// Cursor over a POSIX `TZ` rule.
+struct Parser<'a>(&'a [u8]);
+
+impl Parser<'_> {
+    fn expect(&mut self, c: u8) -> Option<()> {
+        let (&first, rest) = self.0.split_first()?;
+        self.0 = rest;
+        (first == c).then_some(())
+    }
+
+    /// Zone abbreviation: 3 or more letters, or anything within `<…>`.
+    fn name(&mut self) -> Option<()> {
+        let len = if self.0.first() == Some(&b'<') {
+            self.0.iter().position(|&c| c == b'>')? + 1
+        } else {
+            self.0
+                .iter()
+                .take_while(|c| c.is_ascii_alphabetic())
+                .count()
+        };
+        self.0 = &self.0[len..];
+        (len >= 3).then_some(())
+    }
+
+    fn number(&mut self, max: i64) -> Option<i64> {
+        let len = self.0.iter().take_while(|c| c.is_ascii_digit()).count();
+        let (digits, rest) = self.0.split_at(len);
+        self.0 = rest;
+        let n = std::str::from_utf8(digits).ok()?.parse().ok()?;
+        (n <= max).then_some(n)
+    }
+
+    /// `[+-]hh[:mm[:ss]]` in seconds, with hours up to `max_hours`.
+    fn offset(&mut self, max_hours: i64) -> Option<i64> {
+        let sign = match self.0.first() {
+            Some(b'-') => -1,
+            _ => 1,
+        };
+        if matches!(self.0.first(), Some(b'+' | b'-')) {
+            self.0 = &self.0[1..];
+        }
+        let mut seconds = 3600 * self.number(max_hours)?;
+        for unit in [60, 1] {
+            if self.0.first() != Some(&b':') {
+                break;
+            }
+            self.0 = &self.0[1..];
+            seconds += unit * self.number(59)?;
+        }
+        Some(sign * seconds)
+    }
+
+    /// `Jn`, `n` or `Mm.w.d`, then an optional `/time` (02:00 by default).
+    fn transition(&mut self) -> Option<Transition> {
+        let day = match self.0.first()? {
+            b'J' => {
+                self.0 = &self.0[1..];
+                RuleDay::Julian(self.number(365).filter(|&n| n >= 1)?)
+            }
+            b'M' => {
+                self.0 = &self.0[1..];
+                let month = self.number(12).filter(|&m| m >= 1)?;
+                self.expect(b'.')?;
+                let week = self.number(5).filter(|&w| w >= 1)?;
+                self.expect(b'.')?;
+                let weekday = self.number(6)?;
+                RuleDay::Weekday {
+                    month,
+                    week,
+                    weekday,
+                }
+            }
+            _ => RuleDay::Ordinal(self.number(365)?),
+        };
+        let time = if self.0.first() == Some(&b'/') {
+            self.0 = &self.0[1..];
+            self.offset(167)?
+        } else {
+            7200
+        };
+        Some(Transition { day, time })
+    }
+}
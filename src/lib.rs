@@ -1,6 +1,10 @@
+#[cfg(feature = "astro")]
+pub mod astro;
 pub mod calendars;
 pub mod core;
+#[cfg(feature = "tables")]
+pub(crate) mod tables;
 pub mod util;
 
 // Re-export common types for easier use
-pub use core::{context::Context, epoch::EpochDay, traits::convert};
+pub use core::{context::Context, date::CalDate, epoch::EpochDay, moment::Moment, traits::convert};
@@ -0,0 +1,5 @@
+//! Data tables enabled by the `tables` feature.
+
+#[cfg(feature = "astro")]
+pub(crate) mod delta_t;
+pub(crate) mod umm_al_qura;
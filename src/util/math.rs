@@ -4,7 +4,7 @@
 /// # Panics
 /// Panics if `b` is zero.
 #[inline]
-pub fn div_floor(a: i64, b: i64) -> i64 {
+pub const fn div_floor(a: i64, b: i64) -> i64 {
     a.div_euclid(b)
 }
 